@@ -0,0 +1,88 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use i_float::adapter::FloatPointAdapter;
+use i_float::int::point::IntPoint;
+use std::hint::black_box;
+
+fn points(count: usize) -> Vec<[f64; 2]> {
+    let mut result = Vec::with_capacity(count);
+    let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+    for _ in 0..count {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        let x = (seed & 0xFFFF) as f64 * 0.01 - 300.0;
+        let y = ((seed >> 16) & 0xFFFF) as f64 * 0.01 - 300.0;
+        result.push([x, y]);
+    }
+    result
+}
+
+fn float_to_int(c: &mut Criterion) {
+    let points = points(100_000);
+    let adapter = FloatPointAdapter::with_iter(points.iter());
+
+    let mut group = c.benchmark_group("float_to_int");
+    group.bench_function("per_point", |b| {
+        b.iter(|| {
+            let result: Vec<IntPoint> = points.iter().map(|p| adapter.float_to_int(p)).collect();
+            black_box(result)
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| black_box(adapter.float_to_int_slice(&points)))
+    });
+
+    let mut buffer = Vec::with_capacity(points.len());
+    group.bench_function("per_point_reuse", |b| {
+        b.iter(|| {
+            buffer.clear();
+            buffer.extend(points.iter().map(|p| adapter.float_to_int(p)));
+            black_box(buffer.len())
+        })
+    });
+    group.bench_function("extend_reuse", |b| {
+        b.iter(|| {
+            buffer.clear();
+            adapter.float_to_int_extend(&points, &mut buffer);
+            black_box(buffer.len())
+        })
+    });
+    group.finish();
+}
+
+fn int_to_float(c: &mut Criterion) {
+    let points = points(100_000);
+    let adapter = FloatPointAdapter::with_iter(points.iter());
+    let int_points = adapter.float_to_int_slice(&points);
+
+    let mut group = c.benchmark_group("int_to_float");
+    group.bench_function("per_point", |b| {
+        b.iter(|| {
+            let result: Vec<[f64; 2]> = int_points.iter().map(|p| adapter.int_to_float(p)).collect();
+            black_box(result)
+        })
+    });
+    group.bench_function("slice", |b| {
+        b.iter(|| black_box(adapter.int_to_float_slice(&int_points)))
+    });
+
+    let mut buffer = Vec::with_capacity(int_points.len());
+    group.bench_function("per_point_reuse", |b| {
+        b.iter(|| {
+            buffer.clear();
+            buffer.extend(int_points.iter().map(|p| adapter.int_to_float(p)));
+            black_box(buffer.len())
+        })
+    });
+    group.bench_function("extend_reuse", |b| {
+        b.iter(|| {
+            buffer.clear();
+            adapter.int_to_float_extend(&int_points, &mut buffer);
+            black_box(buffer.len())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, float_to_int, int_to_float);
+criterion_main!(benches);
@@ -0,0 +1,208 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use i_float::fix_angle::{FixAngle, FixTrigonometry};
+use i_float::fix_float::{FIX_MAX, FixFloat, FixMath};
+use i_float::fix_vec::FixVec;
+use i_float::i128::Int128;
+use i_float::u128::UInt128;
+use std::hint::black_box;
+
+const COUNT: usize = 10_000;
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        min + (self.next() % (max - min + 1) as u64) as i64
+    }
+}
+
+fn fix_pairs(count: usize) -> Vec<(FixFloat, FixFloat)> {
+    let mut random = Random(0x2545_F491_4F6C_DD1D);
+    (0..count)
+        .map(|_| {
+            let a = random.range(-FIX_MAX, FIX_MAX);
+            let b = random.range(1, FIX_MAX) * if random.next() & 1 == 0 { 1 } else { -1 };
+            (a, b)
+        })
+        .collect()
+}
+
+fn fix_arithmetic(c: &mut Criterion) {
+    let pairs = fix_pairs(COUNT);
+    let float_pairs: Vec<(f64, f64)> = pairs.iter().map(|&(a, b)| (a.f64(), b.f64())).collect();
+
+    let mut group = c.benchmark_group("fix_arithmetic");
+    group.bench_function("fix_mul", |b| {
+        b.iter(|| pairs.iter().fold(0i64, |s, &(x, y)| s.wrapping_add(x.fix_mul(y))))
+    });
+    group.bench_function("f64_mul", |b| {
+        b.iter(|| float_pairs.iter().fold(0.0, |s, &(x, y)| s + x * y))
+    });
+    group.bench_function("fix_div", |b| {
+        b.iter(|| pairs.iter().fold(0i64, |s, &(x, y)| s.wrapping_add(x.fix_div(y))))
+    });
+    group.bench_function("f64_div", |b| {
+        b.iter(|| float_pairs.iter().fold(0.0, |s, &(x, y)| s + x / y))
+    });
+    group.bench_function("fix_sqrt", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .fold(0i64, |s, &(x, _)| s.wrapping_add(x.abs().fix_sqrt()))
+        })
+    });
+    group.bench_function("f64_sqrt", |b| {
+        b.iter(|| float_pairs.iter().fold(0.0, |s, &(x, _)| s + x.abs().sqrt()))
+    });
+    group.finish();
+}
+
+fn wide_multiply(c: &mut Criterion) {
+    let mut random = Random(0x9E37_79B9_7F4A_7C15);
+    let full: Vec<(u64, u64)> = (0..COUNT).map(|_| (random.next(), random.next())).collect();
+    // small operands take the single multiplication path
+    let small: Vec<(u64, u64)> = full.iter().map(|&(a, b)| (a >> 40, b >> 40)).collect();
+    let signed: Vec<(i64, i64)> = full.iter().map(|&(a, b)| (a as i64, b as i64)).collect();
+
+    let mut group = c.benchmark_group("wide_multiply");
+    group.bench_function("u128_multiply_full", |b| {
+        b.iter(|| {
+            full.iter().fold(0u64, |s, &(x, y)| {
+                s ^ UInt128::multiply(black_box(x), black_box(y)).high
+            })
+        })
+    });
+    group.bench_function("native_u128_full", |b| {
+        b.iter(|| {
+            full.iter().fold(0u64, |s, &(x, y)| {
+                s ^ ((black_box(x) as u128 * black_box(y) as u128) >> 64) as u64
+            })
+        })
+    });
+    group.bench_function("u128_multiply_small", |b| {
+        b.iter(|| {
+            small.iter().fold(0u64, |s, &(x, y)| {
+                s ^ UInt128::multiply(black_box(x), black_box(y)).low
+            })
+        })
+    });
+    group.bench_function("native_u128_small", |b| {
+        b.iter(|| {
+            small.iter().fold(0u64, |s, &(x, y)| {
+                s ^ (black_box(x) as u128 * black_box(y) as u128) as u64
+            })
+        })
+    });
+    group.bench_function("i128_mul", |b| {
+        b.iter(|| {
+            signed.iter().fold(0i64, |s, &(x, y)| {
+                s ^ Int128::mul(black_box(x), black_box(y)).high
+            })
+        })
+    });
+    group.bench_function("native_i128_mul", |b| {
+        b.iter(|| {
+            signed.iter().fold(0i64, |s, &(x, y)| {
+                s ^ ((black_box(x) as i128 * black_box(y) as i128) >> 64) as i64
+            })
+        })
+    });
+    group.bench_function("i128_sign_of_det2", |b| {
+        b.iter(|| {
+            signed.windows(2).fold(0i32, |s, w| {
+                s + Int128::sign_of_det2(w[0].0, w[0].1, w[1].0, w[1].1)
+            })
+        })
+    });
+    group.bench_function("native_i128_sign_of_det2", |b| {
+        b.iter(|| {
+            signed.windows(2).fold(0i32, |s, w| {
+                let det = w[0].0 as i128 * w[1].1 as i128 - w[0].1 as i128 * w[1].0 as i128;
+                s + det.signum() as i32
+            })
+        })
+    });
+    group.finish();
+}
+
+fn trigonometry(c: &mut Criterion) {
+    let mut random = Random(0xD1B5_4A32_D192_ED03);
+    let angles: Vec<FixAngle> = (0..COUNT).map(|_| random.range(-4096, 4096)).collect();
+    let radians: Vec<f64> = angles.iter().map(|a| a.radians_f64()).collect();
+
+    let mut group = c.benchmark_group("trigonometry");
+    group.bench_function("fix_sin", |b| {
+        b.iter(|| angles.iter().fold(0i64, |s, a| s.wrapping_add(a.sin())))
+    });
+    group.bench_function("fix_cos", |b| {
+        b.iter(|| angles.iter().fold(0i64, |s, a| s.wrapping_add(a.cos())))
+    });
+    group.bench_function("fix_rotator", |b| {
+        b.iter(|| {
+            angles.iter().fold(0i64, |s, a| {
+                let r = a.rotator();
+                s.wrapping_add(r.x ^ r.y)
+            })
+        })
+    });
+    group.bench_function("f64_sin", |b| {
+        b.iter(|| radians.iter().fold(0.0, |s, r| s + r.sin()))
+    });
+    group.bench_function("f64_sin_cos", |b| {
+        b.iter(|| {
+            radians.iter().fold(0.0, |s, r| {
+                let (sin, cos) = r.sin_cos();
+                s + sin + cos
+            })
+        })
+    });
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut random = Random(0xA076_1D64_78BD_642F);
+    let limit = 1 << 20;
+    let vectors: Vec<FixVec> = (0..COUNT)
+        .map(|_| {
+            loop {
+                let v = FixVec::new(random.range(-limit, limit), random.range(-limit, limit));
+                if v.x.abs().max(v.y.abs()) >= 64 {
+                    break v;
+                }
+            }
+        })
+        .collect();
+    let float_vectors: Vec<(f64, f64)> = vectors.iter().map(|v| (v.x.f64(), v.y.f64())).collect();
+
+    let mut group = c.benchmark_group("normalize");
+    group.bench_function("fix_normalize", |b| {
+        b.iter(|| {
+            vectors.iter().fold(0i64, |s, v| {
+                let n = v.fix_normalize();
+                s.wrapping_add(n.x ^ n.y)
+            })
+        })
+    });
+    group.bench_function("fix_length", |b| {
+        b.iter(|| vectors.iter().fold(0i64, |s, v| s.wrapping_add(v.fix_length())))
+    });
+    group.bench_function("f64_normalize", |b| {
+        b.iter(|| {
+            float_vectors.iter().fold(0.0, |s, &(x, y)| {
+                let l = (x * x + y * y).sqrt();
+                s + x / l + y / l
+            })
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, fix_arithmetic, wide_multiply, trigonometry, normalize);
+criterion_main!(benches);
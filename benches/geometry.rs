@@ -0,0 +1,208 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use i_float::bulk;
+use i_float::fix_vec::FixVec;
+use i_float::float::rect::FloatRect;
+use i_float::int::point::IntPoint;
+use i_float::int::rect::IntRect;
+use i_float::triangle::Triangle;
+use std::hint::black_box;
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn coord(&mut self, limit: i32) -> i32 {
+        ((self.next() % (2 * limit as u64 + 1)) as i64 - limit as i64) as i32
+    }
+}
+
+fn int_points(count: usize, limit: i32) -> Vec<IntPoint> {
+    let mut random = Random(0x2545_F491_4F6C_DD1D);
+    (0..count)
+        .map(|_| IntPoint::new(random.coord(limit), random.coord(limit)))
+        .collect()
+}
+
+fn triangle(c: &mut Criterion) {
+    let points = int_points(30_000, 1 << 20);
+    let fix_points: Vec<FixVec> = points.iter().map(|&p| FixVec::new_point(p)).collect();
+    let float_points: Vec<(f64, f64)> = points.iter().map(|p| (p.x as f64, p.y as f64)).collect();
+
+    let mut group = c.benchmark_group("triangle");
+    group.bench_function("area_two_point", |b| {
+        b.iter(|| {
+            points.windows(3).fold(0i64, |s, w| {
+                s.wrapping_add(Triangle::area_two_point(w[0], w[1], w[2]))
+            })
+        })
+    });
+    group.bench_function("f64_area_two", |b| {
+        b.iter(|| {
+            float_points.windows(3).fold(0.0, |s, w| {
+                let (x0, y0) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+                let (x1, y1) = (w[1].0 - w[2].0, w[1].1 - w[2].1);
+                s + (x0 * y1 - x1 * y0)
+            })
+        })
+    });
+    group.bench_function("is_clockwise_point", |b| {
+        b.iter(|| {
+            points
+                .windows(3)
+                .filter(|w| Triangle::is_clockwise_point(w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.bench_function("is_clockwise", |b| {
+        b.iter(|| {
+            fix_points
+                .windows(3)
+                .filter(|w| Triangle::is_clockwise(w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.bench_function("is_clockwise_exact", |b| {
+        b.iter(|| {
+            fix_points
+                .windows(3)
+                .filter(|w| Triangle::is_clockwise_exact(w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.bench_function("is_contain_point", |b| {
+        b.iter(|| {
+            points
+                .windows(4)
+                .filter(|w| Triangle::is_contain_point(w[3], w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.bench_function("is_contain_exact", |b| {
+        b.iter(|| {
+            fix_points
+                .windows(4)
+                .filter(|w| Triangle::is_contain_exact(w[3], w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.finish();
+
+    // coordinates beyond 2^30 leave the fast path of the exact predicates
+    let wide: Vec<FixVec> = {
+        let mut random = Random(0x9E37_79B9_7F4A_7C15);
+        (0..30_000)
+            .map(|_| FixVec::new(random.next() as i64 >> 2, random.next() as i64 >> 2))
+            .collect()
+    };
+    let mut group = c.benchmark_group("triangle_wide");
+    group.bench_function("is_clockwise_exact", |b| {
+        b.iter(|| {
+            wide.windows(3)
+                .filter(|w| Triangle::is_clockwise_exact(w[0], w[1], w[2]))
+                .count()
+        })
+    });
+    group.bench_function("native_i128_area_sign", |b| {
+        b.iter(|| {
+            wide.windows(3).fold(0i64, |s, w| {
+                let (x0, y0) = (w[1].x as i128 - w[0].x as i128, w[1].y as i128 - w[0].y as i128);
+                let (x1, y1) = (w[1].x as i128 - w[2].x as i128, w[1].y as i128 - w[2].y as i128);
+                s + (x0 * y1 - x1 * y0).signum() as i64
+            })
+        })
+    });
+    group.finish();
+}
+
+fn rect(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rect_with_iter");
+    for count in [1_000, 100_000, 1_000_000] {
+        let points = int_points(count, i32::MAX);
+        let float_points: Vec<[f64; 2]> = points.iter().map(|p| [p.x as f64, p.y as f64]).collect();
+
+        group.bench_with_input(BenchmarkId::new("int", count), &points, |b, points| {
+            b.iter(|| black_box(IntRect::with_iter(points.iter())))
+        });
+        group.bench_with_input(BenchmarkId::new("float", count), &float_points, |b, points| {
+            b.iter(|| black_box(FloatRect::with_iter(points.iter())))
+        });
+    }
+    group.finish();
+}
+
+fn bulk(c: &mut Criterion) {
+    // scalar against the default path, which runs on wide lanes with the simd feature
+    let points = int_points(100_000, 1 << 20);
+    let others = int_points(100_000, 1 << 10);
+    let a = IntPoint::new(-1000, 20);
+    let b = IntPoint::new(3000, -700);
+
+    let mut group = c.benchmark_group("bulk");
+    group.bench_function("bounding_box_scalar", |bench| {
+        bench.iter(|| black_box(bulk::scalar::bounding_box(black_box(&points))))
+    });
+    group.bench_function("bounding_box", |bench| {
+        bench.iter(|| black_box(bulk::bounding_box(black_box(&points))))
+    });
+
+    let mut buffer = points.clone();
+    group.bench_function("translate_scalar", |bench| {
+        bench.iter(|| bulk::scalar::translate(&mut buffer, black_box(IntPoint::new(3, -5))))
+    });
+    group.bench_function("translate", |bench| {
+        bench.iter(|| bulk::translate(&mut buffer, black_box(IntPoint::new(3, -5))))
+    });
+    group.bench_function("scale_scalar", |bench| {
+        bench.iter(|| bulk::scalar::scale(&mut buffer, black_box(-1)))
+    });
+    group.bench_function("scale", |bench| {
+        bench.iter(|| bulk::scale(&mut buffer, black_box(-1)))
+    });
+
+    let mut signs = vec![0i8; points.len()];
+    group.bench_function("orientation_signs_scalar", |bench| {
+        bench.iter(|| bulk::scalar::orientation_signs(black_box(a), black_box(b), &points, &mut signs))
+    });
+    group.bench_function("orientation_signs", |bench| {
+        bench.iter(|| bulk::orientation_signs(black_box(a), black_box(b), &points, &mut signs))
+    });
+
+    let mut products = vec![0i64; points.len()];
+    group.bench_function("dot_products_scalar", |bench| {
+        bench.iter(|| bulk::scalar::dot_products(black_box(&points), black_box(&others), &mut products))
+    });
+    group.bench_function("dot_products", |bench| {
+        bench.iter(|| bulk::dot_products(black_box(&points), black_box(&others), &mut products))
+    });
+
+    let vectors: Vec<FixVec> = points.iter().map(|&p| FixVec::new_point(p)).collect();
+    let other_vectors: Vec<FixVec> = others.iter().map(|&p| FixVec::new_point(p)).collect();
+    let mut vector_buffer = vectors.clone();
+    let offset = FixVec::new(3, -5);
+    group.bench_function("translate_fix_vec_scalar", |bench| {
+        bench.iter(|| bulk::scalar::translate_fix_vec(&mut vector_buffer, black_box(offset)))
+    });
+    group.bench_function("translate_fix_vec", |bench| {
+        bench.iter(|| bulk::translate_fix_vec(&mut vector_buffer, black_box(offset)))
+    });
+    group.bench_function("dot_products_fix_vec_scalar", |bench| {
+        bench.iter(|| {
+            bulk::scalar::dot_products_fix_vec(black_box(&vectors), black_box(&other_vectors), &mut products)
+        })
+    });
+    group.bench_function("dot_products_fix_vec", |bench| {
+        bench.iter(|| {
+            bulk::dot_products_fix_vec(black_box(&vectors), black_box(&other_vectors), &mut products)
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, triangle, rect, bulk);
+criterion_main!(benches);
@@ -0,0 +1,20 @@
+// Regenerates the golden vector files in tests/golden.
+// Run it only when a change of the output is intended and review the diff of the files:
+//
+// cargo run --example generate_golden
+
+#[path = "../tests/golden/suites.rs"]
+mod suites;
+
+fn main() {
+    let dir = suites::golden_dir();
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for suite in suites::SUITES.iter() {
+        let path = dir.join(format!("{}.csv", suite.name));
+        let text = suites::render(suite);
+        let rows = text.lines().count() - 1;
+        std::fs::write(&path, text).unwrap();
+        println!("{}: {} rows", path.display(), rows);
+    }
+}
@@ -0,0 +1,338 @@
+use crate::adapter::{AdapterError, INT_HEADROOM_LOG2, fits_int_headroom, scaled_to_i32};
+use crate::float::compatible::FloatPointCompatible;
+use crate::float::number::FloatNumber;
+use crate::float::rect::FloatRect;
+use crate::int::point::IntPoint;
+
+// Each axis gets its own power of two scale, so very thin data keeps its precision
+// on the short axis. The int space is stretched: orientation (the sign of a cross product)
+// is preserved, but lengths, distances and angles are not. That is why there is no
+// `len_float_to_int` / `sqr_float_to_int` here, use the per axis length conversions instead.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone)]
+pub struct AnisotropicFloatPointAdapter<P: FloatPointCompatible<T>, T: FloatNumber> {
+    pub dir_scale_x: T,
+    pub dir_scale_y: T,
+    pub inv_scale_x: T,
+    pub inv_scale_y: T,
+    pub offset: P,
+    pub rect: FloatRect<T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> AnisotropicFloatPointAdapter<P, T> {
+    #[inline]
+    pub fn new(rect: FloatRect<T>) -> Self {
+        let a = rect.width() * FloatNumber::from_float(0.5);
+        let b = rect.height() * FloatNumber::from_float(0.5);
+
+        let x = rect.min_x + a;
+        let y = rect.min_y + b;
+
+        let offset = P::from_xy(x, y);
+
+        let (dir_scale_x, inv_scale_x) = Self::axis_scale(a);
+        let (dir_scale_y, inv_scale_y) = Self::axis_scale(b);
+
+        Self {
+            dir_scale_x,
+            dir_scale_y,
+            inv_scale_x,
+            inv_scale_y,
+            offset,
+            rect,
+        }
+    }
+
+    // fails when a scale is not finite and positive or pushes its axis out of the i32 headroom
+    pub fn with_scale(rect: FloatRect<T>, scale_x: f64, scale_y: f64) -> Result<Self, AdapterError> {
+        if !rect.is_finite() {
+            return Err(AdapterError::NonFinite);
+        }
+
+        if !(scale_x.is_finite() && scale_x > 0.0 && scale_y.is_finite() && scale_y > 0.0) {
+            return Err(AdapterError::InvalidScale);
+        }
+
+        let a = rect.width() * FloatNumber::from_float(0.5);
+        let b = rect.height() * FloatNumber::from_float(0.5);
+
+        let ex = (a + axis_slack(a, T::from_float(1.0 / scale_x))).to_f64();
+        let ey = (b + axis_slack(b, T::from_float(1.0 / scale_y))).to_f64();
+        if !(fits_int_headroom(ex, scale_x) && fits_int_headroom(ey, scale_y)) {
+            return Err(AdapterError::Overflow);
+        }
+
+        let x = rect.min_x + a;
+        let y = rect.min_y + b;
+
+        let offset = P::from_xy(x, y);
+
+        Ok(Self {
+            dir_scale_x: FloatNumber::from_float(scale_x),
+            dir_scale_y: FloatNumber::from_float(scale_y),
+            inv_scale_x: FloatNumber::from_float(1.0 / scale_x),
+            inv_scale_y: FloatNumber::from_float(1.0 / scale_y),
+            offset,
+            rect,
+        })
+    }
+
+    #[inline]
+    pub fn with_iter<'a, I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a P>,
+        T: FloatNumber,
+        P: 'a,
+    {
+        Self::new(FloatRect::with_iter(iter).unwrap_or(FloatRect::zero()))
+    }
+
+    #[inline]
+    fn axis_scale(half: T) -> (T, T) {
+        // degenerate case
+        if half == FloatNumber::from_float(0.0) {
+            return (FloatNumber::from_float(1.0), FloatNumber::from_float(1.0));
+        }
+
+        let log2 = half.log2().to_i32();
+        let e = (INT_HEADROOM_LOG2 - log2) as f64;
+
+        (
+            FloatNumber::from_float(libm::exp2(e)),
+            FloatNumber::from_float(libm::exp2(-e)),
+        )
+    }
+
+    // the check slack of an axis comes from its own extent, a slack taken from the long axis
+    // would let a point far outside the short one through and saturate in to_i32
+    #[inline(always)]
+    fn contains(&self, x: T, y: T) -> bool {
+        let rect = &self.rect;
+        let sx = axis_slack(rect.width() * FloatNumber::from_float(0.5), self.inv_scale_x);
+        let sy = axis_slack(rect.height() * FloatNumber::from_float(0.5), self.inv_scale_y);
+        rect.min_x - sx <= x && x <= rect.max_x + sx && rect.min_y - sy <= y && y <= rect.max_y + sy
+    }
+
+    #[inline(always)]
+    pub fn int_to_float(&self, point: &IntPoint) -> P {
+        let fx: T = FloatNumber::from_i32(point.x);
+        let fy: T = FloatNumber::from_i32(point.y);
+        let x = fx * self.inv_scale_x + self.offset.x();
+        let y = fy * self.inv_scale_y + self.offset.y();
+        let float = P::from_xy(x, y);
+
+        if cfg!(debug_assertions) && !self.contains(x, y) {
+            panic!(
+                "You are trying to convert a point[{}, {}] which is out of rect: {}",
+                x, y, self.rect
+            );
+        }
+
+        float
+    }
+
+    #[inline(always)]
+    pub fn float_to_int(&self, point: &P) -> IntPoint {
+        if cfg!(debug_assertions) && !self.contains(point.x(), point.y()) {
+            panic!(
+                "You are trying to convert a point[{}, {}] which is out of rect: {}",
+                point.x(),
+                point.y(),
+                self.rect
+            );
+        }
+        let x = ((point.x() - self.offset.x()) * self.dir_scale_x).to_i32();
+        let y = ((point.y() - self.offset.y()) * self.dir_scale_y).to_i32();
+        IntPoint { x, y }
+    }
+
+    #[inline]
+    pub fn try_int_to_float(&self, point: &IntPoint) -> Result<P, AdapterError> {
+        let fx: T = FloatNumber::from_i32(point.x);
+        let fy: T = FloatNumber::from_i32(point.y);
+        let x = fx * self.inv_scale_x + self.offset.x();
+        let y = fy * self.inv_scale_y + self.offset.y();
+
+        if !(x.is_finite() && y.is_finite()) {
+            return Err(AdapterError::NonFinite);
+        }
+
+        if !self.contains(x, y) {
+            return Err(AdapterError::OutOfRange);
+        }
+
+        Ok(P::from_xy(x, y))
+    }
+
+    #[inline]
+    pub fn try_float_to_int(&self, point: &P) -> Result<IntPoint, AdapterError> {
+        if !(point.x().is_finite() && point.y().is_finite()) {
+            return Err(AdapterError::NonFinite);
+        }
+
+        if !self.contains(point.x(), point.y()) {
+            return Err(AdapterError::OutOfRange);
+        }
+
+        let x = scaled_to_i32((point.x() - self.offset.x()) * self.dir_scale_x)?;
+        let y = scaled_to_i32((point.y() - self.offset.y()) * self.dir_scale_y)?;
+
+        Ok(IntPoint { x, y })
+    }
+
+    #[inline(always)]
+    pub fn len_x_float_to_int(&self, value: T) -> i32 {
+        (self.dir_scale_x * value).to_f64() as i32
+    }
+
+    #[inline(always)]
+    pub fn len_y_float_to_int(&self, value: T) -> i32 {
+        (self.dir_scale_y * value).to_f64() as i32
+    }
+
+    #[inline(always)]
+    pub fn len_x_int_to_float(&self, value: i32) -> T {
+        T::from_i32(value) * self.inv_scale_x
+    }
+
+    #[inline(always)]
+    pub fn len_y_int_to_float(&self, value: i32) -> T {
+        T::from_i32(value) * self.inv_scale_y
+    }
+
+    #[inline(always)]
+    pub fn is_uniform(&self) -> bool {
+        self.dir_scale_x == self.dir_scale_y
+    }
+}
+
+// 1% of the full extent, the same share FloatPointAdapter allows, but at least one int unit,
+// so a degenerate axis still tolerates rounding
+#[inline(always)]
+fn axis_slack<T: FloatNumber>(half: T, inv_scale: T) -> T {
+    (half * T::from_float(0.02)).max(inv_scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::AdapterError;
+    use crate::adapter::FloatPointAdapter;
+    use crate::adapter::anisotropic::AnisotropicFloatPointAdapter;
+    use crate::float::compatible::FloatPointCompatible;
+    use crate::float::rect::FloatRect;
+
+    #[test]
+    fn test_0() {
+        let rect = FloatRect::new(0.0, 1_000_000.0, 0.0, 1.0);
+        let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::new(rect);
+        let uniform = FloatPointAdapter::<[f64; 2], f64>::new(rect);
+
+        assert!(!adapter.is_uniform());
+        assert_eq!(adapter.dir_scale_x, uniform.dir_scale);
+        assert!(adapter.dir_scale_y > 1_000.0 * uniform.dir_scale);
+
+        let f0 = [123_456.789, 0.123_456_789];
+        let a = adapter.int_to_float(&adapter.float_to_int(&f0));
+        let b = uniform.int_to_float(&uniform.float_to_int(&f0));
+
+        assert!((a.y() - f0.y()).abs() < 0.000_000_01);
+        assert!((a.y() - f0.y()).abs() < (b.y() - f0.y()).abs());
+    }
+
+    #[test]
+    fn test_1() {
+        let points = [[0.0, 5.0], [0.0, 5.0]];
+        let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::with_iter(points.iter());
+
+        assert_eq!(adapter.dir_scale_x, 1.0);
+        assert_eq!(adapter.dir_scale_y, 1.0);
+        assert!(adapter.is_uniform());
+    }
+
+    #[test]
+    fn test_2() {
+        let rect = FloatRect::new(-10.0, 10.0, -0.01, 0.01);
+        let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::new(rect);
+
+        let lx = adapter.len_x_float_to_int(1.0);
+        let ly = adapter.len_y_float_to_int(0.001);
+
+        assert!((adapter.len_x_int_to_float(lx) - 1.0).abs() < 0.000_001);
+        assert!((adapter.len_y_int_to_float(ly) - 0.001).abs() < 0.000_000_1);
+
+        assert_eq!(
+            adapter.try_float_to_int(&[0.0, 1.0]),
+            Err(AdapterError::OutOfRange)
+        );
+        assert_eq!(
+            adapter.try_float_to_int(&[f64::NAN, 0.0]),
+            Err(AdapterError::NonFinite)
+        );
+        assert!(adapter.try_float_to_int(&[10.0, -0.01]).is_ok());
+    }
+
+    #[test]
+    fn test_3() {
+        let rect = FloatRect::new(0.0, 1_000.0, 0.0, 0.001);
+        let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::new(rect);
+
+        // inside the slack of the long axis, far outside the short one
+        assert_eq!(
+            adapter.try_float_to_int(&[500.0, 5.0]),
+            Err(AdapterError::OutOfRange)
+        );
+        assert!(adapter.try_float_to_int(&[1_005.0, 0.001_01]).is_ok());
+    }
+
+    #[test]
+    fn test_4() {
+        let rect = FloatRect::new(-10.0, 10.0, -1.0, 1.0);
+        type Adapter = AnisotropicFloatPointAdapter<[f64; 2], f64>;
+
+        assert!(Adapter::with_scale(rect, 1_000.0, 10_000.0).is_ok());
+        assert_eq!(
+            Adapter::with_scale(rect, 0.0, 1.0).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::with_scale(rect, 1.0, -1.0).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::with_scale(rect, f64::NAN, 1.0).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::with_scale(rect, 1.0, f64::INFINITY).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::with_scale(rect, 1.0e8, 1.0).err(),
+            Some(AdapterError::Overflow)
+        );
+        assert_eq!(
+            Adapter::with_scale(rect, 1.0, 1.0e9).err(),
+            Some(AdapterError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_5() {
+        let points = [[0.0, 5.0], [10.0, 5.0]];
+        let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::with_iter(points.iter());
+
+        let p = [3.3, 5.0];
+        let q: [f64; 2] = adapter.int_to_float(&adapter.float_to_int(&p));
+        assert!((q.x() - p.x()).abs() < 0.000_001);
+        assert_eq!(q.y(), p.y());
+
+        // rounding noise on the degenerate axis
+        let noisy = [7.0, 5.0 + 4.0 * f64::EPSILON];
+        assert!(adapter.try_float_to_int(&noisy).is_ok());
+        assert_eq!(adapter.float_to_int(&noisy).y, 0);
+        assert_eq!(
+            adapter.try_float_to_int(&[7.0, 7.0]),
+            Err(AdapterError::OutOfRange)
+        );
+    }
+}
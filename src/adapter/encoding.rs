@@ -0,0 +1,273 @@
+use crate::adapter::{AdapterError, FloatPointAdapter};
+use crate::float::compatible::FloatPointCompatible;
+use crate::float::number::FloatNumber;
+use crate::float::rect::FloatRect;
+use alloc::vec::Vec;
+
+// Binary layout, little endian:
+// [version: u8][float width in bytes: u8][scale kind: u8]
+// scale kind 0: [exponent: i16], dir_scale = 2^e, inv_scale = 2^-e
+// scale kind 1: [dir_scale: T][inv_scale: T]
+// [offset x: T][offset y: T][min_x: T][max_x: T][min_y: T][max_y: T]
+pub const ADAPTER_ENCODING_VERSION: u8 = 1;
+
+const SCALE_EXPONENT: u8 = 0;
+const SCALE_RAW: u8 = 1;
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatPointAdapter<P, T> {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AdapterError> {
+        let width = encoded_width::<T>()?;
+        let mut bytes = Vec::with_capacity(3 + 2 * width + 6 * width);
+
+        bytes.push(ADAPTER_ENCODING_VERSION);
+        bytes.push(width as u8);
+
+        if let Some(e) = self.scale_exponent() {
+            bytes.push(SCALE_EXPONENT);
+            bytes.extend_from_slice(&(e as i16).to_le_bytes());
+        } else {
+            bytes.push(SCALE_RAW);
+            write_number(&mut bytes, self.dir_scale);
+            write_number(&mut bytes, self.inv_scale);
+        }
+
+        write_number(&mut bytes, self.offset.x());
+        write_number(&mut bytes, self.offset.y());
+
+        write_number(&mut bytes, self.rect.min_x);
+        write_number(&mut bytes, self.rect.max_x);
+        write_number(&mut bytes, self.rect.min_y);
+        write_number(&mut bytes, self.rect.max_y);
+
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AdapterError> {
+        let mut reader = Reader { bytes, pos: 0 };
+
+        if reader.byte()? != ADAPTER_ENCODING_VERSION {
+            return Err(AdapterError::InvalidEncoding);
+        }
+
+        if reader.byte()? as usize != encoded_width::<T>()? {
+            return Err(AdapterError::InvalidEncoding);
+        }
+
+        let (dir_scale, inv_scale) = match reader.byte()? {
+            SCALE_EXPONENT => {
+                let e = i16::from_le_bytes([reader.byte()?, reader.byte()?]) as i32;
+                let ef = e as f64;
+                (T::from_float(libm::exp2(ef)), T::from_float(libm::exp2(-ef)))
+            }
+            SCALE_RAW => (reader.number()?, reader.number()?),
+            _ => return Err(AdapterError::InvalidEncoding),
+        };
+
+        let offset = P::from_xy(reader.number()?, reader.number()?);
+        let rect = FloatRect::<T>::new(
+            reader.number()?,
+            reader.number()?,
+            reader.number()?,
+            reader.number()?,
+        );
+
+        if reader.pos != bytes.len() {
+            return Err(AdapterError::InvalidEncoding);
+        }
+
+        // the bytes may come from anywhere, accept only what the constructors can produce
+        let zero = T::from_float(0.0);
+        let is_valid_scale = |s: T| s.is_finite() && s > zero;
+        let is_valid_rect = rect.min_x.is_finite()
+            && rect.max_x.is_finite()
+            && rect.min_y.is_finite()
+            && rect.max_y.is_finite()
+            && rect.min_x <= rect.max_x
+            && rect.min_y <= rect.max_y;
+
+        if !is_valid_scale(dir_scale)
+            || !is_valid_scale(inv_scale)
+            || !offset.x().is_finite()
+            || !offset.y().is_finite()
+            || !is_valid_rect
+        {
+            return Err(AdapterError::InvalidEncoding);
+        }
+
+        Ok(Self {
+            dir_scale,
+            inv_scale,
+            offset,
+            rect,
+        })
+    }
+}
+
+// only f32 and f64 have a layout
+#[inline]
+fn encoded_width<T: FloatNumber>() -> Result<usize, AdapterError> {
+    match T::bit_width() {
+        32 => Ok(4),
+        64 => Ok(8),
+        _ => Err(AdapterError::InvalidEncoding),
+    }
+}
+
+#[inline]
+fn write_number<T: FloatNumber>(bytes: &mut Vec<u8>, value: T) {
+    if T::bit_width() == 32 {
+        bytes.extend_from_slice(&(value.to_f64() as f32).to_le_bytes());
+    } else {
+        bytes.extend_from_slice(&value.to_f64().to_le_bytes());
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    #[inline]
+    fn byte(&mut self) -> Result<u8, AdapterError> {
+        let value = *self.bytes.get(self.pos).ok_or(AdapterError::InvalidEncoding)?;
+        self.pos += 1;
+        Ok(value)
+    }
+
+    #[inline]
+    fn number<T: FloatNumber>(&mut self) -> Result<T, AdapterError> {
+        let width = T::bit_width() as usize / 8;
+        let end = self.pos + width;
+        let slice = self
+            .bytes
+            .get(self.pos..end)
+            .ok_or(AdapterError::InvalidEncoding)?;
+        self.pos = end;
+
+        let value = if width == 4 {
+            f32::from_le_bytes(slice.try_into().unwrap()) as f64
+        } else {
+            f64::from_le_bytes(slice.try_into().unwrap())
+        };
+
+        Ok(T::from_float(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::encoding::ADAPTER_ENCODING_VERSION;
+    use crate::adapter::{AdapterError, FloatPointAdapter};
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let points = [[-2.3, -4.1], [-2.0, 3.7], [5.9, 3.0], [5.1, -4.0]];
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::with_iter(points.iter());
+
+        let bytes = adapter.to_bytes().unwrap();
+        assert_eq!(bytes[0], ADAPTER_ENCODING_VERSION);
+        assert_eq!(bytes.len(), 3 + 2 + 6 * 8);
+
+        let restored = FloatPointAdapter::<[f64; 2], f64>::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.dir_scale.to_bits(), adapter.dir_scale.to_bits());
+        assert_eq!(restored.inv_scale.to_bits(), adapter.inv_scale.to_bits());
+        assert_eq!(restored.offset, adapter.offset);
+        assert_eq!(restored.rect, adapter.rect);
+
+        for p in [
+            IntPoint::new(0, 0),
+            IntPoint::new(-123_456, 987_654),
+            IntPoint::new(7, -1),
+        ] {
+            let a = adapter.int_to_float(&p);
+            let b = restored.int_to_float(&p);
+            assert_eq!(a[0].to_bits(), b[0].to_bits());
+            assert_eq!(a[1].to_bits(), b[1].to_bits());
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let rect = FloatRect::new(-1.5f32, 2.25, 0.1, 0.7);
+        let adapter = FloatPointAdapter::<[f32; 2], f32>::with_scale(rect, 1000.0);
+
+        let bytes = adapter.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 3 + 2 * 4 + 6 * 4);
+
+        let restored = FloatPointAdapter::<[f32; 2], f32>::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.dir_scale.to_bits(), adapter.dir_scale.to_bits());
+        assert_eq!(restored.inv_scale.to_bits(), adapter.inv_scale.to_bits());
+
+        let p = IntPoint::new(-311, 42);
+        assert_eq!(adapter.int_to_float(&p), restored.int_to_float(&p));
+    }
+
+    #[test]
+    fn test_2() {
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::new(FloatRect::new(0.0, 1.0, 0.0, 1.0));
+        let bytes = adapter.to_bytes().unwrap();
+
+        let wrong_width = FloatPointAdapter::<[f32; 2], f32>::from_bytes(&bytes);
+        assert!(matches!(wrong_width, Err(AdapterError::InvalidEncoding)));
+
+        let short = FloatPointAdapter::<[f64; 2], f64>::from_bytes(&bytes[..bytes.len() - 1]);
+        assert!(matches!(short, Err(AdapterError::InvalidEncoding)));
+
+        let mut version = bytes.clone();
+        version[0] = 99;
+        let unknown = FloatPointAdapter::<[f64; 2], f64>::from_bytes(&version);
+        assert!(matches!(unknown, Err(AdapterError::InvalidEncoding)));
+
+        let mut long = bytes;
+        long.push(0);
+        let long = FloatPointAdapter::<[f64; 2], f64>::from_bytes(&long);
+        assert!(matches!(long, Err(AdapterError::InvalidEncoding)));
+    }
+
+    #[test]
+    fn test_3() {
+        let rect = FloatRect::new(-1.0, 3.0, 0.0, 2.0);
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::with_scale(rect, 1000.0);
+        let bytes = adapter.to_bytes().unwrap();
+
+        // scale kind 1: dir_scale at 3, inv_scale at 11, offset at 19, rect at 35
+        let patched = |at: usize, value: f64| {
+            let mut b = bytes.clone();
+            b[at..at + 8].copy_from_slice(&value.to_le_bytes());
+            FloatPointAdapter::<[f64; 2], f64>::from_bytes(&b)
+        };
+
+        assert!(patched(3, 1000.0).is_ok());
+        assert!(matches!(patched(3, 0.0), Err(AdapterError::InvalidEncoding)));
+        assert!(matches!(patched(3, -1000.0), Err(AdapterError::InvalidEncoding)));
+        assert!(matches!(
+            patched(11, f64::INFINITY),
+            Err(AdapterError::InvalidEncoding)
+        ));
+        assert!(matches!(
+            patched(19, f64::NAN),
+            Err(AdapterError::InvalidEncoding)
+        ));
+        assert!(matches!(
+            patched(35, f64::NEG_INFINITY),
+            Err(AdapterError::InvalidEncoding)
+        ));
+        assert!(matches!(patched(35, 10.0), Err(AdapterError::InvalidEncoding)));
+    }
+
+    #[test]
+    fn test_4() {
+        let adapter = FloatPointAdapter::<[f32; 2], f32>::new(FloatRect::new(0.0, 1.0, 0.0, 1.0));
+        let mut bytes = adapter.to_bytes().unwrap();
+
+        // scale kind 0 with an exponent past the f32 range
+        assert_eq!(bytes[2], 0);
+        bytes[3..5].copy_from_slice(&300i16.to_le_bytes());
+        let overflow = FloatPointAdapter::<[f32; 2], f32>::from_bytes(&bytes);
+        assert!(matches!(overflow, Err(AdapterError::InvalidEncoding)));
+    }
+}
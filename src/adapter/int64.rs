@@ -0,0 +1,206 @@
+use crate::adapter::{AdapterError, checked_in_rect, debug_check_in_rect, fixed_layout, pow2_layout};
+use crate::float::compatible::FloatPointCompatible;
+use crate::float::number::FloatNumber;
+use crate::float::rect::FloatRect;
+use crate::int::point64::IntPoint64;
+
+// The max half extent is scaled to 2^61, which keeps 2 bits of headroom in i64
+// for the check radius and for sums of points.
+const INT64_HEADROOM_LOG2: i32 = 61;
+
+// Same as FloatPointAdapter but maps into IntPoint64.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone)]
+pub struct FloatPointAdapter64<P: FloatPointCompatible<T>, T: FloatNumber> {
+    pub dir_scale: T,
+    pub inv_scale: T,
+    pub offset: P,
+    pub rect: FloatRect<T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatPointAdapter64<P, T> {
+    #[inline]
+    pub fn new(rect: FloatRect<T>) -> Self {
+        let (offset, dir_scale, inv_scale) = pow2_layout(&rect, INT64_HEADROOM_LOG2);
+
+        Self {
+            dir_scale,
+            inv_scale,
+            offset,
+            rect,
+        }
+    }
+
+    #[inline]
+    pub fn with_scale(rect: FloatRect<T>, scale: f64) -> Self {
+        let (offset, dir_scale, inv_scale) = fixed_layout(&rect, scale);
+
+        Self {
+            dir_scale,
+            inv_scale,
+            offset,
+            rect,
+        }
+    }
+
+    #[inline]
+    pub fn with_iter<'a, I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a P>,
+        T: FloatNumber,
+        P: 'a,
+    {
+        Self::new(FloatRect::with_iter(iter).unwrap_or(FloatRect::zero()))
+    }
+
+    #[inline(always)]
+    pub fn precision(&self) -> T {
+        self.inv_scale
+    }
+
+    #[inline(always)]
+    pub fn int_to_float(&self, point: &IntPoint64) -> P {
+        let fx: T = FloatNumber::from_i64(point.x);
+        let fy: T = FloatNumber::from_i64(point.y);
+        let x = fx * self.inv_scale + self.offset.x();
+        let y = fy * self.inv_scale + self.offset.y();
+        let float = P::from_xy(x, y);
+
+        debug_check_in_rect(&self.rect, &float);
+
+        float
+    }
+
+    #[inline(always)]
+    pub fn float_to_int(&self, point: &P) -> IntPoint64 {
+        debug_check_in_rect(&self.rect, point);
+        let x = ((point.x() - self.offset.x()) * self.dir_scale).to_i64();
+        let y = ((point.y() - self.offset.y()) * self.dir_scale).to_i64();
+        IntPoint64 { x, y }
+    }
+
+    #[inline]
+    pub fn try_int_to_float(&self, point: &IntPoint64) -> Result<P, AdapterError> {
+        let fx: T = FloatNumber::from_i64(point.x);
+        let fy: T = FloatNumber::from_i64(point.y);
+        let x = fx * self.inv_scale + self.offset.x();
+        let y = fy * self.inv_scale + self.offset.y();
+
+        checked_in_rect(&self.rect, x, y)
+    }
+
+    #[inline]
+    pub fn try_float_to_int(&self, point: &P) -> Result<IntPoint64, AdapterError> {
+        checked_in_rect::<P, T>(&self.rect, point.x(), point.y())?;
+
+        let x = scaled_to_i64((point.x() - self.offset.x()) * self.dir_scale)?;
+        let y = scaled_to_i64((point.y() - self.offset.y()) * self.dir_scale)?;
+
+        Ok(IntPoint64 { x, y })
+    }
+
+    #[inline(always)]
+    pub fn sqr_float_to_int(&self, value: T) -> u128 {
+        let scale = self.dir_scale;
+        let sqr_scale = scale * scale;
+        (sqr_scale * value).to_f64() as u128
+    }
+
+    #[inline(always)]
+    pub fn len_float_to_int(&self, value: T) -> i64 {
+        (self.dir_scale * value).to_f64() as i64
+    }
+}
+
+#[inline(always)]
+fn scaled_to_i64<T: FloatNumber>(value: T) -> Result<i64, AdapterError> {
+    // i64::MIN and i64::MAX + 1 are both exact powers of two in f64
+    let f = libm::round(value.to_f64());
+    if f >= i64::MIN as f64 && f < i64::MAX as f64 {
+        Ok(value.to_i64())
+    } else {
+        Err(AdapterError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::AdapterError;
+    use crate::adapter::int64::FloatPointAdapter64;
+    use crate::float::compatible::FloatPointCompatible;
+    use crate::float::rect::FloatRect;
+    use crate::int::point64::IntPoint64;
+
+    #[test]
+    fn test_0() {
+        let rect = FloatRect {
+            min_x: 1.0,
+            max_x: 1.0,
+            min_y: -2.0,
+            max_y: -2.0,
+        };
+
+        let adapter = FloatPointAdapter64::<[f64; 2], f64>::new(rect);
+
+        assert_eq!(adapter.dir_scale, 1.0);
+        assert_eq!(adapter.inv_scale, 1.0);
+    }
+
+    #[test]
+    fn test_1() {
+        // about the size of the Earth in meters
+        let points = [[-20_037_508.34, -20_037_508.34], [20_037_508.34, 20_037_508.34]];
+        let adapter = FloatPointAdapter64::with_iter(points.iter());
+
+        assert!(adapter.precision() < 0.000_001);
+
+        let f0 = [12_345_678.901_234, -7_654_321.098_765];
+        let p0 = adapter.float_to_int(&f0);
+        let f1: [f64; 2] = adapter.int_to_float(&p0);
+
+        assert_eq!(f0, f1);
+        assert!(p0.x.unsigned_abs() < 1 << 62);
+
+        let p1 = adapter.float_to_int(&[20_037_508.34, -20_037_508.34]);
+        assert!(p1.x < 1 << 62);
+        assert!(p1.y > -(1 << 62));
+    }
+
+    #[test]
+    fn test_2() {
+        let points = [[-2.0, -4.0], [-2.0, 3.0], [5.0, 3.0], [5.0, -4.0]];
+        let adapter = FloatPointAdapter64::with_iter(points.iter());
+
+        for p in points.iter() {
+            let q = adapter.try_float_to_int(p).unwrap();
+            let f: [f64; 2] = adapter.try_int_to_float(&q).unwrap();
+            assert_eq!(f.x(), p.x());
+            assert_eq!(f.y(), p.y());
+        }
+
+        assert_eq!(
+            adapter.try_float_to_int(&[f64::NAN, 0.0]),
+            Err(AdapterError::NonFinite)
+        );
+        assert_eq!(
+            adapter.try_float_to_int(&[100.0, 0.0]),
+            Err(AdapterError::OutOfRange)
+        );
+        assert_eq!(
+            adapter.try_int_to_float(&IntPoint64::new(i64::MAX, 0)),
+            Err(AdapterError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_3() {
+        let rect = FloatRect::new(-1.0, 1.0, -1.0, 1.0);
+        let adapter = FloatPointAdapter64::<[f64; 2], f64>::with_scale(rect, 1.0e19);
+
+        assert_eq!(adapter.try_float_to_int(&[1.0, 0.0]), Err(AdapterError::Overflow));
+        assert_eq!(
+            adapter.try_float_to_int(&[0.5, 0.0]),
+            Ok(IntPoint64::new(5_000_000_000_000_000_000, 0))
+        );
+    }
+}
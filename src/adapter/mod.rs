@@ -0,0 +1,812 @@
+use crate::float::compatible::FloatPointCompatible;
+use crate::float::number::FloatNumber;
+use crate::float::rect::FloatRect;
+use crate::int::point::IntPoint;
+use alloc::vec::Vec;
+use core::fmt;
+
+pub mod anisotropic;
+pub mod encoding;
+pub mod int64;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterError {
+    NonFinite,
+    OutOfRange,
+    Overflow,
+    InvalidStep,
+    InvalidScale,
+    InvalidEncoding,
+}
+
+impl fmt::Display for AdapterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdapterError::NonFinite => write!(f, "point has a non-finite coordinate"),
+            AdapterError::OutOfRange => write!(f, "point is out of the adapter rect"),
+            AdapterError::Overflow => write!(f, "scaled point does not fit into the integer type"),
+            AdapterError::InvalidStep => write!(f, "grid step must be finite and positive"),
+            AdapterError::InvalidScale => write!(f, "scale must be finite and positive"),
+            AdapterError::InvalidEncoding => write!(f, "adapter bytes are malformed or of unknown version"),
+        }
+    }
+}
+
+impl core::error::Error for AdapterError {}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone)]
+pub struct FloatPointAdapter<P: FloatPointCompatible<T>, T: FloatNumber> {
+    pub dir_scale: T,
+    pub inv_scale: T,
+    pub offset: P,
+    pub rect: FloatRect<T>,
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber> FloatPointAdapter<P, T> {
+    #[inline]
+    pub fn new(rect: FloatRect<T>) -> Self {
+        let (offset, dir_scale, inv_scale) = pow2_layout(&rect, INT_HEADROOM_LOG2);
+
+        Self {
+            dir_scale,
+            inv_scale,
+            offset,
+            rect,
+        }
+    }
+
+    #[inline]
+    pub fn with_scale(rect: FloatRect<T>, scale: f64) -> Self {
+        let (offset, dir_scale, inv_scale) = fixed_layout(&rect, scale);
+
+        Self {
+            dir_scale,
+            inv_scale,
+            offset,
+            rect,
+        }
+    }
+
+    // fails when the scale is not finite and positive or pushes the rect out of the i32 headroom
+    pub fn try_with_scale(rect: FloatRect<T>, scale: f64) -> Result<Self, AdapterError> {
+        if !rect.is_finite() {
+            return Err(AdapterError::NonFinite);
+        }
+
+        if !(scale.is_finite() && scale > 0.0) {
+            return Err(AdapterError::InvalidScale);
+        }
+
+        let half = rect.width().max(rect.height()) * FloatNumber::from_float(0.5);
+        let extent = (half + rect_radius(&rect)).to_f64();
+        if !fits_int_headroom(extent, scale) {
+            return Err(AdapterError::Overflow);
+        }
+
+        Ok(Self::with_scale(rect, scale))
+    }
+
+    // the integer grid is anchored to multiples of `step`, not to the rect,
+    // so any rect quantises shared coordinates to the same lattice
+    pub fn with_grid_step(rect: FloatRect<T>, step: T) -> Result<Self, AdapterError> {
+        if !rect.is_finite() {
+            return Err(AdapterError::NonFinite);
+        }
+
+        let step = step.to_f64();
+        if !(step.is_finite() && step > 0.0) {
+            return Err(AdapterError::InvalidStep);
+        }
+
+        let scale = 1.0 / step;
+
+        let min_x = rect.min_x.to_f64();
+        let max_x = rect.max_x.to_f64();
+        let min_y = rect.min_y.to_f64();
+        let max_y = rect.max_y.to_f64();
+
+        let ox = libm::round(0.5 * (min_x + max_x) * scale) * step;
+        let oy = libm::round(0.5 * (min_y + max_y) * scale) * step;
+
+        let radius = 0.01 * (max_x - min_x).max(max_y - min_y);
+        let dx = (max_x - ox).max(ox - min_x) + radius;
+        let dy = (max_y - oy).max(oy - min_y) + radius;
+
+        // 2^29 at most, below what new() may produce, so sums and differences of points still fit i32
+        if !(fits_int_headroom(dx, scale) && fits_int_headroom(dy, scale)) {
+            return Err(AdapterError::Overflow);
+        }
+
+        let dir_scale = T::from_float(scale);
+        let inv_scale = T::from_float(step);
+
+        Ok(Self {
+            dir_scale,
+            inv_scale,
+            offset: P::from_xy(T::from_float(ox), T::from_float(oy)),
+            rect,
+        })
+    }
+
+    #[inline]
+    pub fn with_tolerance(rect: FloatRect<T>, tolerance: T) -> Result<Self, AdapterError> {
+        // rounding to the nearest grid node moves a point at most half a step per axis
+        Self::with_grid_step(rect, tolerance + tolerance)
+    }
+
+    #[inline]
+    pub fn with_iter<'a, I>(iter: I) -> Self
+    where
+        I: Iterator<Item = &'a P>,
+        T: FloatNumber,
+        P: 'a,
+    {
+        Self::new(FloatRect::with_iter(iter).unwrap_or(FloatRect::zero()))
+    }
+
+    #[inline(always)]
+    pub fn precision(&self) -> T {
+        self.inv_scale
+    }
+
+    // Some(e) when dir_scale is exactly 2^e and inv_scale is exactly 2^-e
+    #[inline]
+    pub fn scale_exponent(&self) -> Option<i32> {
+        pow2_exponent(self.dir_scale, self.inv_scale)
+    }
+
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        self.scale_exponent().is_some()
+    }
+
+    // Upper bound of |int_to_float(float_to_int(p)) - p| per axis for any p inside the rect
+    // (plus the 1% check radius). Rounding to the grid gives half a step, a whole step for f32
+    // where to_i32 adds 0.5 in f32 and can round up above 2^23. Every float operation adds at most
+    // half an ulp of a value not larger than 2 * m, where m is the largest coordinate magnitude.
+    // A power of two scale is applied exactly, any other scale costs a few more roundings.
+    pub fn max_roundtrip_error(&self) -> T {
+        let (eps, grid) = if T::bit_width() == 32 {
+            (f32::EPSILON as f64, 1.0)
+        } else {
+            (f64::EPSILON, 0.5)
+        };
+
+        let radius = rect_radius(&self.rect).to_f64();
+        let m = self
+            .rect
+            .min_x
+            .abs()
+            .max(self.rect.max_x.abs())
+            .max(self.rect.min_y.abs())
+            .max(self.rect.max_y.abs())
+            .to_f64()
+            + radius;
+
+        let operations = if self.is_exact() { 3.0 } else { 6.0 };
+        let error = grid * self.inv_scale.to_f64() + operations * eps * m;
+
+        T::from_float(error)
+    }
+
+    #[inline(always)]
+    pub fn int_to_float(&self, point: &IntPoint) -> P {
+        let fx: T = FloatNumber::from_i32(point.x);
+        let fy: T = FloatNumber::from_i32(point.y);
+        let x = fx * self.inv_scale + self.offset.x();
+        let y = fy * self.inv_scale + self.offset.y();
+        let float = P::from_xy(x, y);
+
+        debug_check_in_rect(&self.rect, &float);
+
+        float
+    }
+
+    #[inline(always)]
+    pub fn float_to_int(&self, point: &P) -> IntPoint {
+        debug_check_in_rect(&self.rect, point);
+        let x = ((point.x() - self.offset.x()) * self.dir_scale).to_i32();
+        let y = ((point.y() - self.offset.y()) * self.dir_scale).to_i32();
+        IntPoint { x, y }
+    }
+
+    #[inline]
+    pub fn try_int_to_float(&self, point: &IntPoint) -> Result<P, AdapterError> {
+        let fx: T = FloatNumber::from_i32(point.x);
+        let fy: T = FloatNumber::from_i32(point.y);
+        let x = fx * self.inv_scale + self.offset.x();
+        let y = fy * self.inv_scale + self.offset.y();
+
+        checked_in_rect(&self.rect, x, y)
+    }
+
+    #[inline]
+    pub fn try_float_to_int(&self, point: &P) -> Result<IntPoint, AdapterError> {
+        checked_in_rect::<P, T>(&self.rect, point.x(), point.y())?;
+
+        let x = scaled_to_i32((point.x() - self.offset.x()) * self.dir_scale)?;
+        let y = scaled_to_i32((point.y() - self.offset.y()) * self.dir_scale)?;
+
+        Ok(IntPoint { x, y })
+    }
+
+    #[inline]
+    pub fn try_int_to_float_slice(&self, points: &[IntPoint]) -> Result<Vec<P>, AdapterError> {
+        points.iter().map(|p| self.try_int_to_float(p)).collect()
+    }
+
+    #[inline]
+    pub fn try_float_to_int_slice(&self, points: &[P]) -> Result<Vec<IntPoint>, AdapterError> {
+        points.iter().map(|p| self.try_float_to_int(p)).collect()
+    }
+
+    #[inline]
+    pub fn float_to_int_slice(&self, points: &[P]) -> Vec<IntPoint> {
+        let mut result = Vec::with_capacity(points.len());
+        self.float_to_int_extend(points, &mut result);
+        result
+    }
+
+    #[inline]
+    pub fn int_to_float_slice(&self, points: &[IntPoint]) -> Vec<P> {
+        let mut result = Vec::with_capacity(points.len());
+        self.int_to_float_extend(points, &mut result);
+        result
+    }
+
+    pub fn float_to_int_extend(&self, points: &[P], out: &mut Vec<IntPoint>) {
+        if cfg!(debug_assertions) {
+            let radius = self.radius();
+            for p in points.iter() {
+                if !self.rect.contains_with_radius(p, radius) {
+                    panic!(
+                        "You are trying to convert a point[{}, {}] which is out of rect: {}",
+                        p.x(),
+                        p.y(),
+                        self.rect
+                    );
+                }
+            }
+        }
+
+        // keep the loop body branch free so the compiler can vectorize it
+        let ox = self.offset.x();
+        let oy = self.offset.y();
+        let scale = self.dir_scale;
+
+        let start = out.len();
+        out.resize(start + points.len(), IntPoint::ZERO);
+        for (r, p) in out[start..].iter_mut().zip(points.iter()) {
+            r.x = ((p.x() - ox) * scale).to_i32();
+            r.y = ((p.y() - oy) * scale).to_i32();
+        }
+    }
+
+    pub fn int_to_float_extend(&self, points: &[IntPoint], out: &mut Vec<P>) {
+        let ox = self.offset.x();
+        let oy = self.offset.y();
+        let scale = self.inv_scale;
+
+        let start = out.len();
+        out.extend(points.iter().map(|p| {
+            let fx: T = FloatNumber::from_i32(p.x);
+            let fy: T = FloatNumber::from_i32(p.y);
+            P::from_xy(fx * scale + ox, fy * scale + oy)
+        }));
+
+        if cfg!(debug_assertions) {
+            let radius = self.radius();
+            for p in out[start..].iter() {
+                if !self.rect.contains_with_radius(p, radius) {
+                    panic!(
+                        "You are trying to convert a point[{}, {}] which is out of rect: {}",
+                        p.x(),
+                        p.y(),
+                        self.rect
+                    );
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn float_to_int_paths(&self, paths: &[Vec<P>]) -> Vec<Vec<IntPoint>> {
+        paths.iter().map(|path| self.float_to_int_slice(path)).collect()
+    }
+
+    #[inline]
+    pub fn int_to_float_paths(&self, paths: &[Vec<IntPoint>]) -> Vec<Vec<P>> {
+        paths.iter().map(|path| self.int_to_float_slice(path)).collect()
+    }
+
+    #[inline(always)]
+    fn radius(&self) -> T {
+        rect_radius(&self.rect)
+    }
+
+    #[inline(always)]
+    pub fn sqr_float_to_int(&self, value: T) -> u64 {
+        let scale = self.dir_scale;
+        let sqr_scale = scale * scale;
+        (sqr_scale * value).to_f64() as u64
+    }
+
+    #[inline(always)]
+    pub fn len_float_to_int(&self, value: T) -> i32 {
+        (self.dir_scale * value).to_f64() as i32
+    }
+}
+
+pub(crate) fn pow2_exponent<T: FloatNumber>(dir_scale: T, inv_scale: T) -> Option<i32> {
+    let scale = dir_scale.to_f64();
+    if !(scale.is_finite() && scale > 0.0) {
+        return None;
+    }
+
+    let bits = scale.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    if bits & ((1 << 52) - 1) != 0 || biased == 0 {
+        return None;
+    }
+
+    let e = biased - 1023;
+    let ef = e as f64;
+    let is_same = T::from_float(libm::exp2(ef)) == dir_scale && T::from_float(libm::exp2(-ef)) == inv_scale;
+
+    if is_same { Some(e) } else { None }
+}
+
+// new() rounds log2 of the max half extent, so the scaled half extent lands in
+// [2^29 / sqrt(2), 2^29 * sqrt(2)) and stays below 2^30 with the 1% check radius.
+// The spare bit keeps sums and differences of two points inside i32. Custom scales
+// are held to the stricter 2^29 for the whole extent, radius included.
+pub(crate) const INT_HEADROOM_LOG2: i32 = 29;
+
+#[inline(always)]
+pub(crate) fn fits_int_headroom(extent: f64, scale: f64) -> bool {
+    extent * scale <= (1 << INT_HEADROOM_LOG2) as f64
+}
+
+#[inline(always)]
+pub(crate) fn rect_radius<T: FloatNumber>(rect: &FloatRect<T>) -> T {
+    rect.height().max(rect.width()) * T::from_float(0.01)
+}
+
+// the layout below is shared by the i32 and the i64 adapters, they differ only in the headroom
+
+// offset in the rect center and the max half extent
+#[inline]
+fn center<P: FloatPointCompatible<T>, T: FloatNumber>(rect: &FloatRect<T>) -> (P, T) {
+    let a = rect.width() * FloatNumber::from_float(0.5);
+    let b = rect.height() * FloatNumber::from_float(0.5);
+
+    let x = rect.min_x + a;
+    let y = rect.min_y + b;
+
+    (P::from_xy(x, y), a.max(b))
+}
+
+// (offset, dir_scale, inv_scale) scaling the max half extent to about 2^headroom_log2
+#[inline]
+pub(crate) fn pow2_layout<P: FloatPointCompatible<T>, T: FloatNumber>(
+    rect: &FloatRect<T>,
+    headroom_log2: i32,
+) -> (P, T, T) {
+    let (offset, max) = center(rect);
+
+    // degenerate case
+    if max == FloatNumber::from_float(0.0) {
+        return (offset, FloatNumber::from_float(1.0), FloatNumber::from_float(1.0));
+    }
+
+    let log2 = max.log2().to_i32();
+    let e = (headroom_log2 - log2) as f64;
+
+    let dir_scale = FloatNumber::from_float(libm::exp2(e));
+    let inv_scale = FloatNumber::from_float(libm::exp2(-e));
+
+    (offset, dir_scale, inv_scale)
+}
+
+// (offset, dir_scale, inv_scale) for a caller chosen scale
+#[inline]
+pub(crate) fn fixed_layout<P: FloatPointCompatible<T>, T: FloatNumber>(
+    rect: &FloatRect<T>,
+    scale: f64,
+) -> (P, T, T) {
+    let (offset, max) = center(rect);
+
+    // degenerate case
+    if max == FloatNumber::from_float(0.0) {
+        return (offset, FloatNumber::from_float(1.0), FloatNumber::from_float(1.0));
+    }
+
+    (
+        offset,
+        FloatNumber::from_float(scale),
+        FloatNumber::from_float(1.0 / scale),
+    )
+}
+
+#[inline(always)]
+pub(crate) fn debug_check_in_rect<P: FloatPointCompatible<T>, T: FloatNumber>(
+    rect: &FloatRect<T>,
+    point: &P,
+) {
+    if cfg!(debug_assertions) && !rect.contains_with_radius(point, rect_radius(rect)) {
+        panic!(
+            "You are trying to convert a point[{}, {}] which is out of rect: {}",
+            point.x(),
+            point.y(),
+            rect
+        );
+    }
+}
+
+#[inline]
+pub(crate) fn checked_in_rect<P: FloatPointCompatible<T>, T: FloatNumber>(
+    rect: &FloatRect<T>,
+    x: T,
+    y: T,
+) -> Result<P, AdapterError> {
+    if !(x.is_finite() && y.is_finite()) {
+        return Err(AdapterError::NonFinite);
+    }
+
+    let point = P::from_xy(x, y);
+    if !rect.contains_with_radius(&point, rect_radius(rect)) {
+        return Err(AdapterError::OutOfRange);
+    }
+
+    Ok(point)
+}
+
+#[inline(always)]
+pub(crate) fn scaled_to_i32<T: FloatNumber>(value: T) -> Result<i32, AdapterError> {
+    // to_i32 rounds half away from zero, so the valid range is half a unit wider
+    let f = value.to_f64();
+    if f > i32::MIN as f64 - 0.5 && f < i32::MAX as f64 + 0.5 {
+        Ok(value.to_i32())
+    } else {
+        Err(AdapterError::Overflow)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::inconsistent_digit_grouping)]
+mod tests {
+    use crate::adapter::{AdapterError, FloatPointAdapter};
+    use crate::float::compatible::FloatPointCompatible;
+    use crate::float::point::FloatPoint;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_0() {
+        let rect = FloatRect {
+            min_x: 1.0,
+            max_x: 1.0,
+            min_y: -2.0,
+            max_y: -2.0,
+        };
+
+        let adapter = FloatPointAdapter::<FloatPoint<f64>, f64>::new(rect);
+
+        assert_eq!(adapter.dir_scale, 1.0);
+        assert_eq!(adapter.inv_scale, 1.0);
+    }
+
+    #[test]
+    fn test_1() {
+        let rect = FloatRect {
+            min_x: 0.0,
+            max_x: 10.0,
+            min_y: 0.0,
+            max_y: 100.0,
+        };
+
+        let adapter = FloatPointAdapter::new(rect);
+
+        let f0 = [10.0, 2.0];
+        let p0 = adapter.float_to_int(&f0);
+        let f1: [f64; 2] = adapter.int_to_float(&p0);
+
+        assert_eq!((f0.x() - f1.x()).abs() < 0.000_0001, true);
+        assert_eq!((f0.y() - f1.y()).abs() < 0.000_0001, true);
+    }
+
+    #[test]
+    fn test_2() {
+        let points = [[-2.0, -4.0], [-2.0, 3.0], [5.0, 3.0], [5.0, -4.0]];
+
+        let adapter = FloatPointAdapter::with_iter(points.iter());
+
+        let f0 = [1.0, 2.0];
+        let p0 = adapter.float_to_int(&f0);
+        let f1: [f64; 2] = adapter.int_to_float(&p0);
+
+        assert_eq!((f0.x() - f1.x()).abs() < 0.000_0001, true);
+        assert_eq!((f0.y() - f1.y()).abs() < 0.000_0001, true);
+    }
+
+    #[test]
+    fn test_3() {
+        let rect = FloatRect::new(0.0, 10.0, 0.0, 100.0);
+        let adapter = FloatPointAdapter::new(rect);
+
+        let p = adapter.try_float_to_int(&[10.0, 2.0]).unwrap();
+        assert_eq!(p, adapter.float_to_int(&[10.0, 2.0]));
+
+        let f: [f64; 2] = adapter.try_int_to_float(&p).unwrap();
+        assert_eq!((f.x() - 10.0).abs() < 0.000_0001, true);
+        assert_eq!((f.y() - 2.0).abs() < 0.000_0001, true);
+
+        assert_eq!(
+            adapter.try_float_to_int(&[f64::NAN, 2.0]),
+            Err(AdapterError::NonFinite)
+        );
+        assert_eq!(
+            adapter.try_float_to_int(&[5.0, f64::INFINITY]),
+            Err(AdapterError::NonFinite)
+        );
+        assert_eq!(
+            adapter.try_float_to_int(&[5.0, 200.0]),
+            Err(AdapterError::OutOfRange)
+        );
+        assert_eq!(
+            adapter.try_float_to_int(&[-2.0, 50.0]),
+            Err(AdapterError::OutOfRange)
+        );
+        assert!(adapter.try_float_to_int(&[-0.5, 50.0]).is_ok());
+
+        let far = IntPoint::new(0, i32::MAX);
+        assert_eq!(adapter.try_int_to_float(&far), Err(AdapterError::OutOfRange));
+    }
+
+    #[test]
+    fn test_4() {
+        let rect = FloatRect::new(-1.0, 1.0, -1.0, 1.0);
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::with_scale(rect, 1.0e10);
+
+        assert_eq!(adapter.try_float_to_int(&[1.0, 0.0]), Err(AdapterError::Overflow));
+        assert_eq!(adapter.try_float_to_int(&[0.0, 0.0]), Ok(IntPoint::ZERO));
+    }
+
+    #[test]
+    fn test_5() {
+        let points: [[f64; 2]; 4] = [[-2.0, -4.0], [-2.0, 3.0], [5.0, 3.0], [5.0, -4.0]];
+        let adapter = FloatPointAdapter::with_iter(points.iter());
+
+        let int_points = adapter.try_float_to_int_slice(&points).unwrap();
+        assert_eq!(int_points.len(), points.len());
+
+        let float_points = adapter.try_int_to_float_slice(&int_points).unwrap();
+        for (a, b) in points.iter().zip(float_points.iter()) {
+            assert_eq!((a.x() - b.x()).abs() < 0.000_0001, true);
+            assert_eq!((a.y() - b.y()).abs() < 0.000_0001, true);
+        }
+
+        let bad = [[-2.0, -4.0], [f64::NAN, 0.0], [100.0, 0.0]];
+        assert_eq!(adapter.try_float_to_int_slice(&bad), Err(AdapterError::NonFinite));
+    }
+
+    #[test]
+    fn test_6() {
+        let points: [[f64; 2]; 5] = [[-2.0, -4.0], [-2.0, 3.0], [0.3, 0.7], [5.0, 3.0], [5.0, -4.0]];
+        let adapter = FloatPointAdapter::with_iter(points.iter());
+
+        let int_points = adapter.float_to_int_slice(&points);
+        let expected: Vec<_> = points.iter().map(|p| adapter.float_to_int(p)).collect();
+        assert_eq!(int_points, expected);
+
+        let float_points = adapter.int_to_float_slice(&int_points);
+        let expected: Vec<[f64; 2]> = int_points.iter().map(|p| adapter.int_to_float(p)).collect();
+        assert_eq!(float_points, expected);
+
+        let mut buffer = vec![IntPoint::ZERO];
+        adapter.float_to_int_extend(&points, &mut buffer);
+        assert_eq!(buffer.len(), 6);
+        assert_eq!(&buffer[1..], int_points.as_slice());
+    }
+
+    #[test]
+    fn test_7() {
+        let paths: Vec<Vec<[f32; 2]>> = vec![
+            vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0]],
+            vec![],
+            vec![[2.0, 2.0], [4.0, 2.0]],
+        ];
+        let adapter = FloatPointAdapter::with_iter(paths.iter().flatten());
+
+        let int_paths = adapter.float_to_int_paths(&paths);
+        assert_eq!(int_paths.len(), 3);
+        assert!(int_paths[1].is_empty());
+        assert_eq!(int_paths[2][0], adapter.float_to_int(&[2.0, 2.0]));
+
+        let float_paths = adapter.int_to_float_paths(&int_paths);
+        for (a, b) in paths.iter().flatten().zip(float_paths.iter().flatten()) {
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_8() {
+        let step = 0.000_001;
+        let a0 =
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(FloatRect::new(0.0, 10.0, 0.0, 10.0), step)
+                .unwrap();
+        let a1 =
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(FloatRect::new(-3.0, 50.0, 1.0, 20.0), step)
+                .unwrap();
+
+        assert_eq!(a0.precision(), step);
+        assert_eq!(a1.precision(), step);
+
+        let points = [
+            [1.0, 2.0],
+            [3.123_456_7, 2.765_432_1],
+            [9.999_999, 5.000_000_5],
+            [7.25, 1.5],
+        ];
+        let d0 = a0.float_to_int(&points[0]) - a1.float_to_int(&points[0]);
+        for p in points.iter() {
+            let q0 = a0.float_to_int(p);
+            let q1 = a1.float_to_int(p);
+            assert_eq!(q0 - q1, d0);
+
+            let f: [f64; 2] = a0.int_to_float(&q0);
+            assert!((f.x() - p.x()).abs() <= 0.5 * step);
+            assert!((f.y() - p.y()).abs() <= 0.5 * step);
+        }
+    }
+
+    #[test]
+    fn test_9() {
+        let rect = FloatRect::new(0.0, 10_000.0, 0.0, 10.0);
+
+        assert_eq!(
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(rect, 0.000_001).err(),
+            Some(AdapterError::Overflow)
+        );
+        assert_eq!(
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(rect, 0.0).err(),
+            Some(AdapterError::InvalidStep)
+        );
+        assert_eq!(
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(rect, f64::NAN).err(),
+            Some(AdapterError::InvalidStep)
+        );
+        assert_eq!(
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(
+                FloatRect::new(0.0, f64::INFINITY, 0.0, 1.0),
+                1.0
+            )
+            .err(),
+            Some(AdapterError::NonFinite)
+        );
+
+        // fits i32, but not the 2^29 headroom
+        assert_eq!(
+            FloatPointAdapter::<[f64; 2], f64>::with_grid_step(rect, 0.000_005).err(),
+            Some(AdapterError::Overflow)
+        );
+
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::with_tolerance(rect, 0.001).unwrap();
+        assert_eq!(adapter.precision(), 0.002);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_10() {
+        let points = [[-2.3, -4.1], [-2.0, 3.7], [5.9, 3.0], [5.1, -4.0]];
+        let adapter = FloatPointAdapter::<[f64; 2], f64>::with_iter(points.iter());
+
+        let json = serde_json::to_string(&adapter).unwrap();
+        let restored: FloatPointAdapter<[f64; 2], f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.dir_scale, adapter.dir_scale);
+        assert_eq!(restored.inv_scale, adapter.inv_scale);
+        assert_eq!(restored.offset, adapter.offset);
+        assert_eq!(restored.rect, adapter.rect);
+
+        let p = IntPoint::new(-123_456, 987_654);
+        assert_eq!(restored.int_to_float(&p), adapter.int_to_float(&p));
+    }
+
+    #[test]
+    fn test_11() {
+        let rect = FloatRect::new(-3.0, 7.0, 1.0, 2.0);
+
+        let a0 = FloatPointAdapter::<[f64; 2], f64>::new(rect);
+        let a1 = FloatPointAdapter::<[f64; 2], f64>::with_scale(rect, 1024.0);
+        let a2 = FloatPointAdapter::<[f64; 2], f64>::with_scale(rect, 1000.0);
+        let a3 = FloatPointAdapter::<[f64; 2], f64>::with_grid_step(rect, 0.25).unwrap();
+
+        assert!(a0.is_exact());
+        assert_eq!(a1.scale_exponent(), Some(10));
+        assert!(!a2.is_exact());
+        assert_eq!(a3.scale_exponent(), Some(2));
+        assert!(a0.max_roundtrip_error() < a2.max_roundtrip_error());
+    }
+
+    #[test]
+    fn test_12() {
+        let rect64 = FloatRect::new(-1_000.0, 3_000.0, 500.0, 2_500.0);
+        let rect32 = FloatRect::new(-1_000.0, 3_000.0, 500.0, 2_500.0);
+
+        let adapters64 = [
+            FloatPointAdapter::<[f64; 2], f64>::new(rect64),
+            FloatPointAdapter::<[f64; 2], f64>::with_scale(rect64, 333_333.3),
+        ];
+        let adapters32 = [
+            FloatPointAdapter::<[f32; 2], f32>::new(rect32),
+            FloatPointAdapter::<[f32; 2], f32>::with_scale(rect32, 333.3),
+        ];
+
+        let mut seed = 0x9E37_79B9_7F4A_7C15_u64;
+        for _ in 0..10_000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let u = (seed & 0xFF_FFFF) as f64 / 0xFF_FFFF as f64;
+            let v = ((seed >> 24) & 0xFF_FFFF) as f64 / 0xFF_FFFF as f64;
+            let x = -1_000.0 + 4_000.0 * u;
+            let y = 500.0 + 2_000.0 * v;
+
+            for adapter in adapters64.iter() {
+                let p = [x, y];
+                let q: [f64; 2] = adapter.int_to_float(&adapter.float_to_int(&p));
+                let max = adapter.max_roundtrip_error();
+                assert!((p.x() - q.x()).abs() <= max);
+                assert!((p.y() - q.y()).abs() <= max);
+            }
+
+            for adapter in adapters32.iter() {
+                let p = [x as f32, y as f32];
+                let q: [f32; 2] = adapter.int_to_float(&adapter.float_to_int(&p));
+                let max = adapter.max_roundtrip_error();
+                assert!((p.x() - q.x()).abs() <= max);
+                assert!((p.y() - q.y()).abs() <= max);
+            }
+        }
+    }
+
+    #[test]
+    fn test_13() {
+        let rect = FloatRect::new(-10.0, 10.0, -1.0, 1.0);
+        type Adapter = FloatPointAdapter<[f64; 2], f64>;
+
+        let adapter = Adapter::try_with_scale(rect, 1024.0).unwrap();
+        assert_eq!(adapter.dir_scale, 1024.0);
+        assert_eq!(adapter.inv_scale, 1.0 / 1024.0);
+
+        assert_eq!(
+            Adapter::try_with_scale(rect, 0.0).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::try_with_scale(rect, -2.0).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::try_with_scale(rect, f64::NAN).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::try_with_scale(rect, f64::INFINITY).err(),
+            Some(AdapterError::InvalidScale)
+        );
+        assert_eq!(
+            Adapter::try_with_scale(rect, 1.0e8).err(),
+            Some(AdapterError::Overflow)
+        );
+        assert_eq!(
+            Adapter::try_with_scale(FloatRect::new(0.0, f64::NAN, 0.0, 1.0), 1.0).err(),
+            Some(AdapterError::NonFinite)
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use crate::fix_vec::FixVec;
+use crate::int::point::IntPoint;
+use crate::int::rect::IntRect;
+
+pub mod scalar;
+#[cfg(feature = "simd")]
+mod simd;
+
+// Slice operations, with the `simd` feature they run on the portable lanes of `wide`.
+// Results are bit-identical to the `scalar` module on every target.
+// Translate, scale and the dot products wrap on overflow.
+// Orientation signs are exact for the full i32 range, points outside [-2^30, 2^30)
+// take the slower exact path of Triangle::area_two_sign.
+// Functions writing into an output slice stop at the shortest input.
+
+#[cfg(feature = "simd")]
+use simd as imp;
+
+#[cfg(not(feature = "simd"))]
+use scalar as imp;
+
+#[inline]
+pub fn bounding_box(points: &[IntPoint]) -> Option<IntRect> {
+    imp::bounding_box(points)
+}
+
+#[inline]
+pub fn translate(points: &mut [IntPoint], offset: IntPoint) {
+    imp::translate(points, offset)
+}
+
+#[inline]
+pub fn scale(points: &mut [IntPoint], factor: i32) {
+    imp::scale(points, factor)
+}
+
+// exact sign of Triangle::area_two_point(a, b, p) for every p
+#[inline]
+pub fn orientation_signs(a: IntPoint, b: IntPoint, points: &[IntPoint], signs: &mut [i8]) {
+    imp::orientation_signs(a, b, points, signs)
+}
+
+#[inline]
+pub fn dot_products(a: &[IntPoint], b: &[IntPoint], result: &mut [i64]) {
+    imp::dot_products(a, b, result)
+}
+
+#[inline]
+pub fn translate_fix_vec(vectors: &mut [FixVec], offset: FixVec) {
+    imp::translate_fix_vec(vectors, offset)
+}
+
+#[inline]
+pub fn dot_products_fix_vec(a: &[FixVec], b: &[FixVec], result: &mut [i64]) {
+    imp::dot_products_fix_vec(a, b, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bulk;
+    use crate::bulk::scalar;
+    use crate::fix_vec::FixVec;
+    use crate::int::point::IntPoint;
+    use crate::int::rect::IntRect;
+    use crate::triangle::Triangle;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn coord(&mut self) -> i32 {
+            match self.next() % 4 {
+                0 => [i32::MIN, i32::MAX, 0, -1][(self.next() % 4) as usize],
+                1 => (self.next() % 9) as i32 - 4,
+                _ => self.next() as i32,
+            }
+        }
+
+        fn point(&mut self) -> IntPoint {
+            IntPoint::new(self.coord(), self.coord())
+        }
+
+        fn points(&mut self, count: usize) -> Vec<IntPoint> {
+            (0..count).map(|_| self.point()).collect()
+        }
+
+        fn fix_vec(&mut self) -> FixVec {
+            FixVec::new(self.next() as i64, self.next() as i64)
+        }
+
+        fn fix_vecs(&mut self, count: usize) -> Vec<FixVec> {
+            (0..count).map(|_| self.fix_vec()).collect()
+        }
+    }
+
+    // runs a check for every slice length below max_len, the lengths cover
+    // the vector body and every tail size
+    fn for_each_len(max_len: usize, mut check: impl FnMut(&mut Random, usize)) {
+        let mut random = Random(0x2545_F491_4F6C_DD1D);
+        for n in 0..max_len {
+            check(&mut random, n);
+        }
+    }
+
+    #[test]
+    fn test_0() {
+        assert_eq!(bulk::bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn test_1() {
+        let points = [
+            IntPoint::new(3, -2),
+            IntPoint::new(-5, 7),
+            IntPoint::new(1, 1),
+            IntPoint::new(0, -9),
+            IntPoint::new(4, 0),
+        ];
+        let rect = bulk::bounding_box(&points).unwrap();
+
+        assert_eq!(rect, IntRect::new(-5, 4, -9, 7));
+        assert_eq!(rect, IntRect::with_iter(points.iter()).unwrap());
+    }
+
+    #[test]
+    fn test_2() {
+        for_each_len(40, |random, n| {
+            let points = random.points(n);
+            assert_eq!(bulk::bounding_box(&points), scalar::bounding_box(&points));
+            if n > 0 {
+                assert_eq!(bulk::bounding_box(&points), IntRect::with_iter(points.iter()));
+            }
+        });
+    }
+
+    #[test]
+    fn test_3() {
+        for_each_len(40, |random, n| {
+            let offset = random.point();
+            let mut a = random.points(n);
+            let mut b = a.clone();
+            bulk::translate(&mut a, offset);
+            scalar::translate(&mut b, offset);
+            assert_eq!(a, b);
+        });
+    }
+
+    #[test]
+    fn test_4() {
+        for_each_len(40, |random, n| {
+            let factor = random.coord();
+            let mut a = random.points(n);
+            let mut b = a.clone();
+            bulk::scale(&mut a, factor);
+            scalar::scale(&mut b, factor);
+            assert_eq!(a, b);
+        });
+    }
+
+    #[test]
+    fn test_5() {
+        for_each_len(80, |random, n| {
+            // odd n keep the edge within i32, even n let it take the full range
+            let shift = if n % 2 == 1 { 2 } else { 0 };
+            let a = IntPoint::new(random.coord() >> shift, random.coord() >> shift);
+            let b = IntPoint::new(random.coord() >> shift, random.coord() >> shift);
+            let points = random.points(n);
+
+            let mut s0 = vec![2i8; n];
+            let mut s1 = vec![2i8; n];
+            bulk::orientation_signs(a, b, &points, &mut s0);
+            scalar::orientation_signs(a, b, &points, &mut s1);
+            assert_eq!(s0, s1);
+        });
+    }
+
+    #[test]
+    fn test_6() {
+        for_each_len(80, |random, n| {
+            let a = random.point();
+            let b = random.point();
+            let points = random.points(n);
+
+            let mut signs = vec![2i8; n];
+            bulk::orientation_signs(a, b, &points, &mut signs);
+
+            let fix = |p: IntPoint| FixVec::new(p.x as i64, p.y as i64);
+            for (p, s) in points.iter().zip(signs.iter()) {
+                assert_eq!(*s as i32, Triangle::area_two_sign(fix(a), fix(b), fix(*p)));
+            }
+        });
+
+        // the area is -(2^32 - 1)^2, far outside of i64
+        let a = IntPoint::new(i32::MIN, i32::MIN);
+        let b = IntPoint::new(i32::MAX, i32::MIN);
+        let points = [IntPoint::new(i32::MIN, i32::MAX); 5];
+        let mut signs = [0i8; 5];
+        bulk::orientation_signs(a, b, &points, &mut signs);
+        assert_eq!(signs, [-1; 5]);
+    }
+
+    #[test]
+    fn test_7() {
+        let a = IntPoint::new(0, 0);
+        let b = IntPoint::new(10, 0);
+        let points = [
+            IntPoint::new(5, 5),
+            IntPoint::new(5, -5),
+            IntPoint::new(20, 0),
+            IntPoint::new(-3, 1),
+            IntPoint::new(7, -1),
+        ];
+        let mut signs = [0i8; 5];
+        bulk::orientation_signs(a, b, &points, &mut signs);
+
+        for (p, s) in points.iter().zip(signs.iter()) {
+            assert_eq!(*s as i64, Triangle::area_two_point(a, b, *p).signum());
+        }
+    }
+
+    #[test]
+    fn test_8() {
+        for_each_len(40, |random, n| {
+            let a = random.points(n);
+            let b = random.points(n);
+
+            let mut r0 = vec![0i64; n];
+            let mut r1 = vec![0i64; n];
+            bulk::dot_products(&a, &b, &mut r0);
+            scalar::dot_products(&a, &b, &mut r1);
+            assert_eq!(r0, r1);
+
+            for i in 0..n {
+                let xx = a[i].x as i64 * b[i].x as i64;
+                let yy = a[i].y as i64 * b[i].y as i64;
+                assert_eq!(r0[i], xx.wrapping_add(yy));
+            }
+        });
+    }
+
+    #[test]
+    fn test_9() {
+        // 2^62 + 2^62 does not fit i64, the sum wraps to i64::MIN in debug builds too
+        let min = IntPoint::new(i32::MIN, i32::MIN);
+        let points = [min, min, min, min, min];
+
+        let mut r0 = [0i64; 5];
+        let mut r1 = [0i64; 5];
+        bulk::dot_products(&points, &points, &mut r0);
+        scalar::dot_products(&points, &points, &mut r1);
+
+        assert_eq!(r0, [i64::MIN; 5]);
+        assert_eq!(r1, [i64::MIN; 5]);
+    }
+
+    #[test]
+    fn test_10() {
+        for_each_len(20, |random, n| {
+            let a = random.fix_vecs(n);
+            let b = random.fix_vecs(n);
+
+            let mut r0 = vec![0i64; n];
+            let mut r1 = vec![0i64; n];
+            bulk::dot_products_fix_vec(&a, &b, &mut r0);
+            scalar::dot_products_fix_vec(&a, &b, &mut r1);
+            assert_eq!(r0, r1);
+        });
+    }
+
+    #[test]
+    fn test_11() {
+        for_each_len(20, |random, n| {
+            let offset = random.fix_vec();
+            let mut v0 = random.fix_vecs(n);
+            let mut v1 = v0.clone();
+            bulk::translate_fix_vec(&mut v0, offset);
+            scalar::translate_fix_vec(&mut v1, offset);
+            assert_eq!(v0, v1);
+        });
+    }
+
+    #[test]
+    fn test_12() {
+        let points = [IntPoint::new(1, 1), IntPoint::new(-1, 1), IntPoint::new(1, -1)];
+        let mut signs = [0i8; 1];
+        bulk::orientation_signs(IntPoint::ZERO, IntPoint::new(1, 0), &points, &mut signs);
+        assert_eq!(signs, [-1]);
+
+        let mut result = [0i64; 2];
+        bulk::dot_products(&points, &points[..1], &mut result);
+        assert_eq!(result, [2, 0]);
+    }
+}
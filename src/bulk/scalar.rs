@@ -0,0 +1,78 @@
+use crate::fix_vec::FixVec;
+use crate::int::point::IntPoint;
+use crate::int::rect::IntRect;
+
+use crate::triangle::Triangle;
+
+// Reference implementations, the simd paths must produce exactly the same results.
+// Translate, scale and dot products wrap like a release build does, orientation signs are exact.
+
+#[inline]
+pub fn bounding_box(points: &[IntPoint]) -> Option<IntRect> {
+    let (first, rest) = points.split_first()?;
+    let mut rect = IntRect::with_point(*first);
+    for p in rest {
+        rect.add_point(p);
+    }
+    Some(rect)
+}
+
+#[inline]
+pub fn translate(points: &mut [IntPoint], offset: IntPoint) {
+    for p in points.iter_mut() {
+        p.x = p.x.wrapping_add(offset.x);
+        p.y = p.y.wrapping_add(offset.y);
+    }
+}
+
+#[inline]
+pub fn scale(points: &mut [IntPoint], factor: i32) {
+    for p in points.iter_mut() {
+        p.x = p.x.wrapping_mul(factor);
+        p.y = p.y.wrapping_mul(factor);
+    }
+}
+
+#[inline]
+pub fn orientation_signs(a: IntPoint, b: IntPoint, points: &[IntPoint], signs: &mut [i8]) {
+    let is_short_edge = is_short(a) && is_short(b);
+    for (p, s) in points.iter().zip(signs.iter_mut()) {
+        *s = if is_short_edge && is_short(*p) {
+            Triangle::area_two_point(a, b, *p).signum() as i8
+        } else {
+            let fix = |p: IntPoint| FixVec::new(p.x as i64, p.y as i64);
+            Triangle::area_two_sign(fix(a), fix(b), fix(*p)) as i8
+        };
+    }
+}
+
+#[inline]
+pub fn dot_products(a: &[IntPoint], b: &[IntPoint], result: &mut [i64]) {
+    for ((pa, pb), r) in a.iter().zip(b.iter()).zip(result.iter_mut()) {
+        let xx = pa.x as i64 * pb.x as i64;
+        let yy = pa.y as i64 * pb.y as i64;
+        *r = xx.wrapping_add(yy);
+    }
+}
+
+#[inline]
+pub fn translate_fix_vec(vectors: &mut [FixVec], offset: FixVec) {
+    for v in vectors.iter_mut() {
+        v.x = v.x.wrapping_add(offset.x);
+        v.y = v.y.wrapping_add(offset.y);
+    }
+}
+
+#[inline]
+pub fn dot_products_fix_vec(a: &[FixVec], b: &[FixVec], result: &mut [i64]) {
+    for ((va, vb), r) in a.iter().zip(b.iter()).zip(result.iter_mut()) {
+        *r = va.x.wrapping_mul(vb.x).wrapping_add(va.y.wrapping_mul(vb.y));
+    }
+}
+
+// both coordinates in [-2^30, 2^30), then area_two_point can not overflow
+#[inline(always)]
+pub(super) fn is_short(p: IntPoint) -> bool {
+    const LIMIT: u32 = 1 << 30;
+    (p.x as u32).wrapping_add(LIMIT) < 2 * LIMIT && (p.y as u32).wrapping_add(LIMIT) < 2 * LIMIT
+}
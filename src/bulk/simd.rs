@@ -0,0 +1,158 @@
+use crate::bulk::scalar;
+use crate::fix_vec::FixVec;
+use crate::int::point::IntPoint;
+use crate::int::rect::IntRect;
+use wide::{CmpGt, CmpLt, i32x4, i64x2, i64x4};
+
+// Portable lanes from `wide`: sse/avx on x86, neon on aarch64, simd128 on wasm and plain arrays
+// elsewhere. Which instructions a lane operation compiles to depends on the target features
+// of the build, e.g. the widening products need sse4.1 on x86 (-C target-cpu=native).
+// Four points go into one i32x4 per axis, tails go to the scalar code.
+// i64 x i64 products have no lane instruction on common targets, so dot_products_fix_vec stays scalar.
+
+pub(super) use scalar::dot_products_fix_vec;
+
+#[inline(always)]
+fn xs(p: &[IntPoint]) -> i32x4 {
+    i32x4::new([p[0].x, p[1].x, p[2].x, p[3].x])
+}
+
+#[inline(always)]
+fn ys(p: &[IntPoint]) -> i32x4 {
+    i32x4::new([p[0].y, p[1].y, p[2].y, p[3].y])
+}
+
+// [x0, y0, x1, y1]
+#[inline(always)]
+fn pair(p: &[IntPoint]) -> i32x4 {
+    i32x4::new([p[0].x, p[0].y, p[1].x, p[1].y])
+}
+
+#[inline]
+pub(super) fn bounding_box(points: &[IntPoint]) -> Option<IntRect> {
+    if points.len() < 8 {
+        return scalar::bounding_box(points);
+    }
+
+    // four independent accumulators hide the latency of min and max
+    let mut min = [
+        pair(points),
+        pair(&points[2..]),
+        pair(&points[4..]),
+        pair(&points[6..]),
+    ];
+    let mut max = min;
+    let chunks = points[8..].chunks_exact(8);
+    let tail = chunks.remainder();
+    for chunk in chunks {
+        for i in 0..4 {
+            let value = pair(&chunk[2 * i..]);
+            min[i] = min[i].min(value);
+            max[i] = max[i].max(value);
+        }
+    }
+
+    let min = min[0].min(min[1]).min(min[2].min(min[3])).to_array();
+    let max = max[0].max(max[1]).max(max[2].max(max[3])).to_array();
+
+    let mut rect = IntRect::new(
+        min[0].min(min[2]),
+        max[0].max(max[2]),
+        min[1].min(min[3]),
+        max[1].max(max[3]),
+    );
+    for p in tail {
+        rect.add_point(p);
+    }
+
+    Some(rect)
+}
+
+#[inline]
+pub(super) fn translate(points: &mut [IntPoint], offset: IntPoint) {
+    let add = i32x4::new([offset.x, offset.y, offset.x, offset.y]);
+    let mut chunks = points.chunks_exact_mut(2);
+    for chunk in chunks.by_ref() {
+        let r = (pair(chunk) + add).to_array();
+        chunk[0] = IntPoint::new(r[0], r[1]);
+        chunk[1] = IntPoint::new(r[2], r[3]);
+    }
+    scalar::translate(chunks.into_remainder(), offset);
+}
+
+#[inline]
+pub(super) fn scale(points: &mut [IntPoint], factor: i32) {
+    let mul = i32x4::splat(factor);
+    let mut chunks = points.chunks_exact_mut(2);
+    for chunk in chunks.by_ref() {
+        let r = (pair(chunk) * mul).to_array();
+        chunk[0] = IntPoint::new(r[0], r[1]);
+        chunk[1] = IntPoint::new(r[2], r[3]);
+    }
+    scalar::scale(chunks.into_remainder(), factor);
+}
+
+#[inline]
+pub(super) fn orientation_signs(a: IntPoint, b: IntPoint, points: &[IntPoint], signs: &mut [i8]) {
+    let n = points.len().min(signs.len());
+    let (points, signs) = (&points[..n], &mut signs[..n]);
+
+    if !(scalar::is_short(a) && scalar::is_short(b)) {
+        scalar::orientation_signs(a, b, points, signs);
+        return;
+    }
+
+    // area = (b - a).x * (b - p).y - (b - p).x * (b - a).y, with every coordinate
+    // in [-2^30, 2^30) the differences fit i32 and the area fits i64
+    let ex = i32x4::splat(b.x - a.x);
+    let ey = i32x4::splat(b.y - a.y);
+    let bx = i32x4::splat(b.x);
+    let by = i32x4::splat(b.y);
+    let shift = i32x4::splat(1 << 30);
+
+    let mut chunks = points.chunks_exact(4);
+    let mut signs_chunks = signs.chunks_exact_mut(4);
+    for (chunk, s) in chunks.by_ref().zip(signs_chunks.by_ref()) {
+        let (px, py) = (xs(chunk), ys(chunk));
+
+        // a lane is short when adding 2^30 keeps it non negative
+        if ((px + shift) | (py + shift)).move_mask() != 0 {
+            scalar::orientation_signs(a, b, chunk, s);
+            continue;
+        }
+
+        let area = ex.mul_widen(by - py) - (bx - px).mul_widen(ey);
+        let sign = (area.cmp_lt(i64x4::ZERO) - area.cmp_gt(i64x4::ZERO)).to_array();
+        for (s, v) in s.iter_mut().zip(sign.iter()) {
+            *s = *v as i8;
+        }
+    }
+
+    scalar::orientation_signs(a, b, chunks.remainder(), signs_chunks.into_remainder());
+}
+
+#[inline]
+pub(super) fn dot_products(a: &[IntPoint], b: &[IntPoint], result: &mut [i64]) {
+    let n = a.len().min(b.len()).min(result.len());
+    let (a, b, result) = (&a[..n], &b[..n], &mut result[..n]);
+
+    let mut ca = a.chunks_exact(4);
+    let mut cb = b.chunks_exact(4);
+    let mut cr = result.chunks_exact_mut(4);
+    for ((pa, pb), r) in ca.by_ref().zip(cb.by_ref()).zip(cr.by_ref()) {
+        // the products are exact, only their sum wraps
+        let dot = xs(pa).mul_widen(xs(pb)) + ys(pa).mul_widen(ys(pb));
+        r.copy_from_slice(&dot.to_array());
+    }
+
+    scalar::dot_products(ca.remainder(), cb.remainder(), cr.into_remainder());
+}
+
+#[inline]
+pub(super) fn translate_fix_vec(vectors: &mut [FixVec], offset: FixVec) {
+    let add = i64x2::new([offset.x, offset.y]);
+    for v in vectors.iter_mut() {
+        let r = (i64x2::new([v.x, v.y]) + add).to_array();
+        *v = FixVec::new(r[0], r[1]);
+    }
+}
@@ -1,11 +1,15 @@
 use crate::fix_float::{FIX_FRACTION_BITS, FIX_ZERO, FixConvert, FixFloat, FixMath};
+use crate::i128::Int128;
 use crate::int::point::IntPoint;
 use core::fmt;
 use core::ops;
 use core::ops::Mul;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct FixVec {
     pub x: FixFloat,
     pub y: FixFloat,
@@ -166,6 +170,16 @@ impl FixVec {
         a - b
     }
 
+    #[inline(always)]
+    pub fn cross_product_sign(self, v: Self) -> i32 {
+        Int128::sign_of_det2(self.x, self.y, v.x, v.y)
+    }
+
+    #[inline(always)]
+    pub fn dot_product_sign(self, v: Self) -> i32 {
+        Int128::sign_of_dot2(self.x, self.y, v.x, v.y)
+    }
+
     #[inline(always)]
     pub fn sqr_distance(self, v: Self) -> i64 {
         (self - v).sqr_length()
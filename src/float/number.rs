@@ -33,6 +33,18 @@ where
     fn to_usize(self) -> usize;
     fn to_f64(self) -> f64;
     fn bit_width() -> u8;
+
+    // added after the first release, the defaults keep external implementations compiling
+
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        libm::round(self.to_f64()) as i64
+    }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.to_f64().is_finite()
+    }
 }
 
 impl FloatNumber for f32 {
@@ -119,6 +131,12 @@ impl FloatNumber for f32 {
         (self + 0.5_f32.copysign(self)) as i32
     }
 
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        // adding 0.5 is not exact once the fraction bits run out
+        libm::roundf(self) as i64
+    }
+
     #[inline(always)]
     fn to_usize(self) -> usize {
         (self + 0.5) as usize
@@ -129,6 +147,11 @@ impl FloatNumber for f32 {
         self as f64
     }
 
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
     #[inline(always)]
     fn bit_width() -> u8 {
         32
@@ -219,6 +242,12 @@ impl FloatNumber for f64 {
         (self + 0.5_f64.copysign(self)) as i32
     }
 
+    #[inline(always)]
+    fn to_i64(self) -> i64 {
+        // adding 0.5 is not exact once the fraction bits run out
+        libm::round(self) as i64
+    }
+
     #[inline(always)]
     fn to_usize(self) -> usize {
         (self + 0.5) as usize
@@ -229,6 +258,11 @@ impl FloatNumber for f64 {
         self
     }
 
+    #[inline(always)]
+    fn is_finite(self) -> bool {
+        self.is_finite()
+    }
+
     #[inline(always)]
     fn bit_width() -> u8 {
         64
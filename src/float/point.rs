@@ -4,7 +4,8 @@ use core::fmt;
 use core::ops::{Add, AddAssign, Mul, Neg, Sub};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct FloatPoint<T: FloatNumber> {
     pub x: T,
     pub y: T,
@@ -2,7 +2,10 @@ use crate::float::compatible::FloatPointCompatible;
 use crate::float::number::FloatNumber;
 use core::fmt;
 
-#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct FloatRect<T: FloatNumber> {
     pub min_x: T,
     pub max_x: T,
@@ -31,6 +34,16 @@ impl<T: FloatNumber> FloatRect<T> {
         }
     }
 
+    #[inline]
+    pub fn try_new(min_x: T, max_x: T, min_y: T, max_y: T) -> Option<Self> {
+        let rect = Self::new(min_x, max_x, min_y, max_y);
+        if rect.is_finite() && rect.is_valid() {
+            Some(rect)
+        } else {
+            None
+        }
+    }
+
     #[inline(always)]
     pub fn zero() -> Self {
         let zero = FloatNumber::from_float(0.0);
@@ -42,6 +55,16 @@ impl<T: FloatNumber> FloatRect<T> {
         }
     }
 
+    #[inline(always)]
+    pub fn with_min_max<P: FloatPointCompatible<T>>(min: P, max: P) -> Self {
+        Self {
+            min_x: min.x(),
+            max_x: max.x(),
+            min_y: min.y(),
+            max_y: max.y(),
+        }
+    }
+
     #[inline]
     pub fn with_point<P: FloatPointCompatible<T>>(point: P) -> Self {
         Self {
@@ -52,6 +75,42 @@ impl<T: FloatNumber> FloatRect<T> {
         }
     }
 
+    #[inline]
+    pub fn try_with_point<P: FloatPointCompatible<T>>(point: P) -> Option<Self> {
+        if point.x().is_finite() && point.y().is_finite() {
+            Some(Self::with_point(point))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn with_ab<P: FloatPointCompatible<T>>(a: P, b: P) -> Self {
+        let (min_x, max_x) = if a.x() < b.x() {
+            (a.x(), b.x())
+        } else {
+            (b.x(), a.x())
+        };
+        let (min_y, max_y) = if a.y() < b.y() {
+            (a.y(), b.y())
+        } else {
+            (b.y(), a.y())
+        };
+
+        Self {
+            min_x,
+            max_x,
+            min_y,
+            max_y,
+        }
+    }
+
+    #[inline]
+    pub fn try_with_ab<P: FloatPointCompatible<T>>(a: P, b: P) -> Option<Self> {
+        let rect = Self::with_ab(a, b);
+        if rect.is_finite() { Some(rect) } else { None }
+    }
+
     #[inline]
     pub fn with_points<P>(points: &[P]) -> Option<Self>
     where
@@ -67,7 +126,8 @@ impl<T: FloatNumber> FloatRect<T> {
         P: FloatPointCompatible<T> + 'a,
         T: FloatNumber,
     {
-        let mut iter = iter;
+        // non-finite points are skipped, they would poison the bounds
+        let mut iter = iter.filter(|p| p.x().is_finite() && p.y().is_finite());
         let first_point = iter.next()?;
         let mut rect = Self {
             min_x: first_point.x(),
@@ -96,14 +156,19 @@ impl<T: FloatNumber> FloatRect<T> {
     pub fn with_optional_rects(rect_0: Option<&Self>, rect_1: Option<&Self>) -> Option<Self> {
         match (rect_0, rect_1) {
             (Some(r0), Some(r1)) => Some(Self::with_rects(r0, r1)),
-            (Some(r0), None) => Some(r0.clone()),
-            (None, Some(r1)) => Some(r1.clone()),
+            (Some(r0), None) => Some(*r0),
+            (None, Some(r1)) => Some(*r1),
             (None, None) => None,
         }
     }
 
+    // a non-finite point is ignored, the same as in with_iter
     #[inline]
     pub fn add_point<P: FloatPointCompatible<T>>(&mut self, point: &P) {
+        if !(point.x().is_finite() && point.y().is_finite()) {
+            return;
+        }
+
         if self.min_x > point.x() {
             self.min_x = point.x()
         }
@@ -150,6 +215,11 @@ impl<T: FloatNumber> FloatRect<T> {
             && point.y() <= self.max_y
     }
 
+    #[inline(always)]
+    pub fn contains_exclude_borders<P: FloatPointCompatible<T>>(&self, point: &P) -> bool {
+        self.min_x < point.x() && point.x() < self.max_x && self.min_y < point.y() && point.y() < self.max_y
+    }
+
     #[inline(always)]
     pub fn contains_with_radius<P: FloatPointCompatible<T>>(&self, point: &P, radius: T) -> bool {
         let min_x = self.min_x - radius;
@@ -158,6 +228,62 @@ impl<T: FloatNumber> FloatRect<T> {
         let max_y = self.max_y + radius;
         min_x <= point.x() && point.x() <= max_x && min_y <= point.y() && point.y() <= max_y
     }
+
+    #[inline]
+    pub fn is_intersect_border_include(&self, other: &Self) -> bool {
+        let x = self.min_x <= other.max_x && self.max_x >= other.min_x;
+        let y = self.min_y <= other.max_y && self.max_y >= other.min_y;
+        x && y
+    }
+
+    #[inline]
+    pub fn is_intersect_border_exclude(&self, other: &Self) -> bool {
+        let x = self.min_x < other.max_x && self.max_x > other.min_x;
+        let y = self.min_y < other.max_y && self.max_y > other.min_y;
+        x && y
+    }
+
+    #[inline]
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        let x = self.min_x <= other.min_x && other.max_x <= self.max_x;
+        let y = self.min_y <= other.min_y && other.max_y <= self.max_y;
+        x && y
+    }
+
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.is_intersect_border_include(other) {
+            return None;
+        }
+
+        let min_x = self.min_x.max(other.min_x);
+        let max_x = self.max_x.min(other.max_x);
+        let min_y = self.min_y.max(other.min_y);
+        let max_y = self.max_y.min(other.max_y);
+
+        Some(Self::new(min_x, max_x, min_y, max_y))
+    }
+
+    #[inline(always)]
+    pub fn area(&self) -> T {
+        self.width() * self.height()
+    }
+
+    #[inline(always)]
+    pub fn center<P: FloatPointCompatible<T>>(&self) -> P {
+        let half = T::from_float(0.5);
+        P::from_xy(half * (self.min_x + self.max_x), half * (self.min_y + self.max_y))
+    }
+
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool {
+        self.min_x.is_finite() && self.max_x.is_finite() && self.min_y.is_finite() && self.max_y.is_finite()
+    }
+
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.min_x <= self.max_x && self.min_y <= self.max_y
+    }
 }
 
 impl<T: FloatNumber> fmt::Display for FloatRect<T> {
@@ -171,6 +297,7 @@ impl<T: FloatNumber> fmt::Display for FloatRect<T> {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::inconsistent_digit_grouping)]
 mod tests {
     use crate::float::rect::FloatRect;
 
@@ -200,4 +327,71 @@ mod tests {
         assert!(FloatRect::with_optional_rects(None, r1).is_some());
         assert!(FloatRect::<f32>::with_optional_rects(None, None).is_none());
     }
+
+    #[test]
+    fn test_2() {
+        let points = [
+            [f64::NAN, 1.0],
+            [-2.0, -4.0],
+            [f64::INFINITY, 0.0],
+            [5.0, 3.0],
+            [0.0, f64::NAN],
+        ];
+
+        let rect: FloatRect<f64> = FloatRect::with_iter(points.iter()).unwrap();
+
+        assert_eq!(rect, FloatRect::new(-2.0, 5.0, -4.0, 3.0));
+
+        let bad = [[f64::NAN, 1.0], [f64::NEG_INFINITY, 0.0]];
+        assert!(FloatRect::<f64>::with_points(&bad).is_none());
+    }
+
+    #[test]
+    fn test_3() {
+        assert!(FloatRect::try_new(0.0, 1.0, 0.0, 1.0).is_some());
+        assert!(FloatRect::try_new(f64::NAN, 1.0, 0.0, 1.0).is_none());
+        assert!(FloatRect::try_new(0.0, f64::INFINITY, 0.0, 1.0).is_none());
+        assert!(FloatRect::try_new(1.0, 0.0, 0.0, 1.0).is_none());
+        assert!(FloatRect::<f32>::try_with_point([0.0, f32::NAN]).is_none());
+        assert!(FloatRect::<f32>::try_with_ab([0.0, 1.0], [2.0, -1.0]).is_some());
+    }
+
+    #[test]
+    fn test_4() {
+        let r0 = FloatRect::new(-10.0, 10.0, -10.0, 10.0);
+        let r1 = FloatRect::new(0.0, 20.0, 5.0, 15.0);
+        let r2 = FloatRect::new(10.0, 20.0, -5.0, 5.0);
+        let r3 = FloatRect::new(-5.0, 5.0, -5.0, 5.0);
+
+        assert!(r0.is_intersect_border_include(&r1));
+        assert!(r0.is_intersect_border_exclude(&r1));
+        assert!(r0.is_intersect_border_include(&r2));
+        assert!(!r0.is_intersect_border_exclude(&r2));
+        assert!(r0.contains_rect(&r3));
+        assert!(!r0.contains_rect(&r1));
+
+        assert_eq!(r0.intersection(&r1), Some(FloatRect::new(0.0, 10.0, 5.0, 10.0)));
+        assert_eq!(r0.intersection(&r2), Some(FloatRect::new(10.0, 10.0, -5.0, 5.0)));
+        assert_eq!(r3.intersection(&r2), None);
+
+        assert_eq!(r0.area(), 400.0);
+        let c: [f64; 2] = r1.center();
+        assert_eq!(c, [10.0, 10.0]);
+
+        assert!(r0.contains(&[10.0, 0.0]));
+        assert!(!r0.contains_exclude_borders(&[10.0, 0.0]));
+        assert!(r0.contains_exclude_borders(&[9.0, 0.0]));
+    }
+
+    #[test]
+    fn test_5() {
+        let mut rect = FloatRect::new(0.0, 1.0, 0.0, 1.0);
+        rect.add_point(&[f64::INFINITY, 0.5]);
+        rect.add_point(&[0.5, f64::NEG_INFINITY]);
+        rect.add_point(&[f64::NAN, 2.0]);
+        assert_eq!(rect, FloatRect::new(0.0, 1.0, 0.0, 1.0));
+
+        rect.add_point(&[2.0, -1.0]);
+        assert_eq!(rect, FloatRect::new(0.0, 2.0, -1.0, 1.0));
+    }
 }
@@ -0,0 +1,254 @@
+use crate::u128::UInt128;
+use core::cmp::Ordering;
+use core::{fmt, ops};
+
+// two's complement, the sign lives in the high word
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Int128 {
+    pub high: i64,
+    pub low: u64,
+}
+
+impl Int128 {
+    pub const ZERO: Self = Self { high: 0, low: 0 };
+    pub const MAX: Self = Self {
+        high: i64::MAX,
+        low: u64::MAX,
+    };
+    pub const MIN: Self = Self {
+        high: i64::MIN,
+        low: 0,
+    };
+
+    #[inline(always)]
+    pub fn new(high: i64, low: u64) -> Self {
+        Self { high, low }
+    }
+
+    #[inline]
+    pub fn mul(a: i64, b: i64) -> Self {
+        let value = UInt128::multiply(a.unsigned_abs(), b.unsigned_abs());
+        let product = Self::from_bits(value);
+        if (a < 0) != (b < 0) {
+            product.wrapping_neg()
+        } else {
+            product
+        }
+    }
+
+    #[inline(always)]
+    fn from_bits(value: UInt128) -> Self {
+        Self::new(value.high as i64, value.low)
+    }
+
+    #[inline(always)]
+    fn to_bits(self) -> UInt128 {
+        UInt128::new(self.high as u64, self.low)
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.high == 0 && self.low == 0
+    }
+
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.high < 0
+    }
+
+    #[inline(always)]
+    pub fn is_positive(&self) -> bool {
+        self.high > 0 || self.high == 0 && self.low != 0
+    }
+
+    #[inline(always)]
+    pub fn signum(&self) -> i32 {
+        if self.is_negative() {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    #[inline]
+    pub fn unsigned_abs(self) -> UInt128 {
+        if self.is_negative() {
+            self.wrapping_neg().to_bits()
+        } else {
+            self.to_bits()
+        }
+    }
+
+    #[inline]
+    pub fn wrapping_neg(self) -> Self {
+        Self::from_bits(UInt128::ZERO.wrapping_sub(self.to_bits()))
+    }
+
+    #[inline]
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let value = Self::from_bits(self.to_bits().wrapping_add(other.to_bits()));
+        // the sum overflows when both terms share a sign the result does not have
+        let overflow = self.is_negative() == other.is_negative() && value.is_negative() != self.is_negative();
+        (value, overflow)
+    }
+
+    #[inline]
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let value = Self::from_bits(self.to_bits().wrapping_sub(other.to_bits()));
+        let overflow = self.is_negative() != other.is_negative() && value.is_negative() != self.is_negative();
+        (value, overflow)
+    }
+
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    // compares a * b with c * d exactly for the full i64 range
+    #[inline]
+    pub fn cmp_products(a: i64, b: i64, c: i64, d: i64) -> Ordering {
+        if let (Some(ab), Some(cd)) = (a.checked_mul(b), c.checked_mul(d)) {
+            return ab.cmp(&cd);
+        }
+        Self::mul(a, b).cmp(&Self::mul(c, d))
+    }
+
+    // sign of the determinant |a b; c d| = a * d - b * c
+    #[inline]
+    pub fn sign_of_det2(a: i64, b: i64, c: i64, d: i64) -> i32 {
+        Self::cmp_products(a, d, b, c) as i32
+    }
+
+    // sign of the dot product of (a, b) and (c, d) = a * c + b * d
+    #[inline]
+    pub fn sign_of_dot2(a: i64, b: i64, c: i64, d: i64) -> i32 {
+        let sum = a
+            .checked_mul(c)
+            .zip(b.checked_mul(d))
+            .and_then(|(ac, bd)| ac.checked_add(bd));
+        if let Some(sum) = sum {
+            return sum.signum() as i32;
+        }
+        // |b * d| <= 2^126, so its negation always fits
+        Self::mul(a, c).cmp(&-Self::mul(b, d)) as i32
+    }
+
+    // sign of the exact sum, partial sums are allowed to leave the i128 range
+    pub fn sign_of_sum(values: &[Int128]) -> i32 {
+        let mut sum = Self::ZERO;
+        let mut wraps: i64 = 0;
+        for &value in values.iter() {
+            let (next, overflow) = sum.overflowing_add(value);
+            if overflow {
+                wraps += if value.is_negative() { -1 } else { 1 };
+            }
+            sum = next;
+        }
+
+        match wraps.cmp(&0) {
+            Ordering::Greater => 1,
+            Ordering::Less => -1,
+            Ordering::Equal => sum.signum(),
+        }
+    }
+
+    #[inline]
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == Self::MIN {
+            None
+        } else {
+            Some(self.wrapping_neg())
+        }
+    }
+}
+
+impl From<i64> for Int128 {
+    #[inline(always)]
+    fn from(value: i64) -> Self {
+        Self::new(value >> 63, value as u64)
+    }
+}
+
+impl From<i128> for Int128 {
+    #[inline(always)]
+    fn from(value: i128) -> Self {
+        Self::new((value >> 64) as i64, value as u64)
+    }
+}
+
+impl From<Int128> for i128 {
+    #[inline(always)]
+    fn from(value: Int128) -> Self {
+        ((value.high as i128) << 64) | value.low as i128
+    }
+}
+
+impl ops::Neg for Int128 {
+    type Output = Int128;
+
+    #[inline(always)]
+    fn neg(self) -> Int128 {
+        debug_assert!(self != Self::MIN, "attempt to negate with overflow");
+        self.wrapping_neg()
+    }
+}
+
+impl ops::Add for Int128 {
+    type Output = Int128;
+
+    #[inline(always)]
+    fn add(self, other: Int128) -> Int128 {
+        let (value, overflow) = self.overflowing_add(other);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        value
+    }
+}
+
+impl ops::Sub for Int128 {
+    type Output = Int128;
+
+    #[inline(always)]
+    fn sub(self, other: Int128) -> Int128 {
+        let (value, overflow) = self.overflowing_sub(other);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        value
+    }
+}
+
+impl fmt::Display for Int128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", i128::from(*self))
+    }
+}
+
+impl PartialOrd for Int128 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Int128 {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let cmp_high = self.high.cmp(&other.high);
+        match cmp_high {
+            Ordering::Equal => self.low.cmp(&other.low),
+            _ => cmp_high,
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod point;
+pub mod point64;
+pub mod rational;
 pub mod rect;
@@ -0,0 +1,180 @@
+use crate::int::point::IntPoint;
+use core::cmp::Ordering;
+use core::{fmt, ops};
+
+// Coordinates are expected in the symmetric range [-i64::MAX, i64::MAX]. FloatPointAdapter64
+// stays far below it. With i64::MIN two squares add up to 2^127, which does not fit i128,
+// so dot_product and sqr_length check the range in debug builds.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntPoint64 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl IntPoint64 {
+    pub const ZERO: Self = Self { x: 0, y: 0 };
+    pub const EMPTY: Self = Self {
+        x: i64::MAX,
+        y: i64::MAX,
+    };
+
+    #[inline(always)]
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    #[inline(always)]
+    pub fn cross_product(self, v: Self) -> i128 {
+        let a = (self.x as i128) * (v.y as i128);
+        let b = (self.y as i128) * (v.x as i128);
+
+        a - b
+    }
+
+    #[inline(always)]
+    pub fn dot_product(self, v: Self) -> i128 {
+        debug_assert!(self.is_symmetric() && v.is_symmetric());
+        let xx = (self.x as i128) * (v.x as i128);
+        let yy = (self.y as i128) * (v.y as i128);
+        xx + yy
+    }
+
+    #[inline(always)]
+    pub fn sqr_length(self) -> i128 {
+        debug_assert!(self.is_symmetric());
+        let x = self.x as i128;
+        let y = self.y as i128;
+        x * x + y * y
+    }
+
+    #[inline(always)]
+    pub fn sqr_distance(self, other: Self) -> i128 {
+        (self - other).sqr_length()
+    }
+
+    #[inline(always)]
+    fn is_symmetric(self) -> bool {
+        self.x != i64::MIN && self.y != i64::MIN
+    }
+}
+
+impl fmt::Display for IntPoint64 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {}]", self.x, self.y)
+    }
+}
+
+impl From<[i64; 2]> for IntPoint64 {
+    #[inline(always)]
+    fn from(value: [i64; 2]) -> Self {
+        IntPoint64::new(value[0], value[1])
+    }
+}
+
+impl From<(i64, i64)> for IntPoint64 {
+    #[inline(always)]
+    fn from(value: (i64, i64)) -> Self {
+        IntPoint64::new(value.0, value.1)
+    }
+}
+
+impl From<IntPoint> for IntPoint64 {
+    #[inline(always)]
+    fn from(value: IntPoint) -> Self {
+        IntPoint64::new(value.x as i64, value.y as i64)
+    }
+}
+
+impl PartialOrd for IntPoint64 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IntPoint64 {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let x = self.x == other.x;
+        if x && self.y == other.y {
+            Ordering::Equal
+        } else if self.x < other.x || x && self.y < other.y {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl ops::Add for IntPoint64 {
+    type Output = IntPoint64;
+
+    #[inline(always)]
+    fn add(self, other: IntPoint64) -> IntPoint64 {
+        IntPoint64 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl ops::Sub for IntPoint64 {
+    type Output = IntPoint64;
+
+    #[inline(always)]
+    fn sub(self, other: IntPoint64) -> IntPoint64 {
+        IntPoint64 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int::point::IntPoint;
+    use crate::int::point64::IntPoint64;
+
+    #[test]
+    fn test_0() {
+        let a = IntPoint64::new(i64::MAX, -i64::MAX);
+        let b = IntPoint64::new(-i64::MAX, i64::MAX);
+
+        let max = i64::MAX as i128;
+
+        assert_eq!(a.cross_product(b), 0);
+        assert_eq!(a.dot_product(b), -2 * max * max);
+        assert_eq!(a.sqr_length(), 2 * max * max);
+
+        // cross product stays in i128 even for i64::MIN
+        let c = IntPoint64::new(i64::MIN, i64::MIN);
+        let d = IntPoint64::new(i64::MIN, i64::MAX);
+        let min = i64::MIN as i128;
+        assert_eq!(c.cross_product(d), min * max - min * min);
+    }
+
+    #[test]
+    fn test_1() {
+        let p: IntPoint64 = IntPoint::new(i32::MIN, 7).into();
+        assert_eq!(p, IntPoint64::new(i32::MIN as i64, 7));
+        assert!(IntPoint64::new(0, 4) < IntPoint64::new(1, 0));
+        assert!(IntPoint64::new(0, 0) < IntPoint64::new(0, 4));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_2() {
+        let _ = IntPoint64::new(i64::MIN, i64::MIN).sqr_length();
+    }
+
+    #[test]
+    fn test_3() {
+        let a = IntPoint64::new(-i64::MAX, -i64::MAX);
+        let max = i64::MAX as i128;
+
+        assert_eq!(a.sqr_length(), 2 * max * max);
+        assert_eq!(a.dot_product(a), 2 * max * max);
+    }
+}
@@ -0,0 +1,440 @@
+use crate::int::point::IntPoint;
+use core::cmp::Ordering;
+use core::fmt;
+
+// Exact point (x / den, y / den). The form is always reduced with den > 0,
+// so the derived equality and hash are exact. The fields are private to keep it that way,
+// deserialization goes through the same checked constructor.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawRationalPoint"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RationalPoint {
+    x: i128,
+    y: i128,
+    den: i128,
+}
+
+impl RationalPoint {
+    #[inline]
+    pub fn new(x: i128, y: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator must not be zero");
+        Self::try_new(x, y, den).expect("coordinate can not be negated")
+    }
+
+    // None for a zero denominator or when the sign can not be moved to the numerators
+    #[inline]
+    pub fn try_new(x: i128, y: i128, den: i128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let (x, y, den) = if den < 0 {
+            (x.checked_neg()?, y.checked_neg()?, den.checked_neg()?)
+        } else {
+            (x, y, den)
+        };
+
+        let g = gcd(gcd(x.unsigned_abs(), y.unsigned_abs()), den.unsigned_abs()) as i128;
+
+        Some(Self {
+            x: x / g,
+            y: y / g,
+            den: den / g,
+        })
+    }
+
+    #[inline(always)]
+    pub fn x(&self) -> i128 {
+        self.x
+    }
+
+    #[inline(always)]
+    pub fn y(&self) -> i128 {
+        self.y
+    }
+
+    #[inline(always)]
+    pub fn den(&self) -> i128 {
+        self.den
+    }
+
+    // The single crossing point of segments a0-a1 and b0-b1, ends included.
+    // None for disjoint, parallel or collinear overlapping segments.
+    pub fn with_segments(a0: IntPoint, a1: IntPoint, b0: IntPoint, b1: IntPoint) -> Option<Self> {
+        let r = a1.subtract(a0);
+        let s = b1.subtract(b0);
+        let e = b0.subtract(a0);
+
+        // every difference is below 2^32, so all crosses are below 2^65
+        let cross = |ux: i64, uy: i64, vx: i64, vy: i64| ux as i128 * vy as i128 - uy as i128 * vx as i128;
+
+        let mut den = cross(r.x, r.y, s.x, s.y);
+        if den == 0 {
+            return None;
+        }
+
+        let mut t = cross(e.x, e.y, s.x, s.y);
+        let mut u = cross(e.x, e.y, r.x, r.y);
+        if den < 0 {
+            den = -den;
+            t = -t;
+            u = -u;
+        }
+
+        if t < 0 || t > den || u < 0 || u > den {
+            return None;
+        }
+
+        // |a0 * den| < 2^96 and |r * t| < 2^97
+        let x = a0.x as i128 * den + r.x as i128 * t;
+        let y = a0.y as i128 * den + r.y as i128 * t;
+
+        Some(Self::new(x, y, den))
+    }
+
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    #[inline]
+    pub fn to_int_point(&self) -> Option<IntPoint> {
+        if self.den != 1 {
+            return None;
+        }
+        let x = i32::try_from(self.x).ok()?;
+        let y = i32::try_from(self.y).ok()?;
+        Some(IntPoint::new(x, y))
+    }
+
+    // Each coordinate goes to the nearest integer, an exact half goes towards +infinity:
+    // 2.5 -> 3, -2.5 -> -2. The same rule for both axes keeps a snap independent of
+    // the direction of the segments. Panics if a coordinate leaves the i32 range,
+    // which can not happen for points made by with_segments.
+    #[inline]
+    pub fn round(&self) -> IntPoint {
+        let x = round_half_up(self.x, self.den);
+        let y = round_half_up(self.y, self.den);
+        IntPoint::new(
+            i32::try_from(x).expect("x is out of i32 range"),
+            i32::try_from(y).expect("y is out of i32 range"),
+        )
+    }
+
+    #[inline]
+    pub fn to_f64(&self) -> (f64, f64) {
+        let den = self.den as f64;
+        (self.x as f64 / den, self.y as f64 / den)
+    }
+
+    #[inline]
+    pub fn cmp_x(&self, other: &Self) -> Ordering {
+        cmp_rational(self.x, self.den, other.x, other.den)
+    }
+
+    #[inline]
+    pub fn cmp_y(&self, other: &Self) -> Ordering {
+        cmp_rational(self.y, self.den, other.y, other.den)
+    }
+
+    // Order of two points lying on the segment a0-a1, moving from a0 to a1.
+    // Collinear points are ordered by x for a non vertical segment and by y for a vertical one.
+    #[inline]
+    pub fn cmp_along(&self, other: &Self, a0: IntPoint, a1: IntPoint) -> Ordering {
+        match a0.x.cmp(&a1.x) {
+            Ordering::Less => self.cmp_x(other),
+            Ordering::Greater => other.cmp_x(self),
+            Ordering::Equal => match a0.y.cmp(&a1.y) {
+                Ordering::Less => self.cmp_y(other),
+                Ordering::Greater => other.cmp_y(self),
+                Ordering::Equal => Ordering::Equal,
+            },
+        }
+    }
+}
+
+impl From<IntPoint> for RationalPoint {
+    #[inline]
+    fn from(value: IntPoint) -> Self {
+        Self {
+            x: value.x as i128,
+            y: value.y as i128,
+            den: 1,
+        }
+    }
+}
+
+impl fmt::Display for RationalPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "[{}, {}]", self.x, self.y)
+        } else {
+            write!(f, "[{}/{}, {}/{}]", self.x, self.den, self.y, self.den)
+        }
+    }
+}
+
+// same order as IntPoint: by x, then by y
+impl PartialOrd for RationalPoint {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RationalPoint {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_x(other).then_with(|| self.cmp_y(other))
+    }
+}
+
+impl PartialEq<IntPoint> for RationalPoint {
+    #[inline]
+    fn eq(&self, other: &IntPoint) -> bool {
+        self.den == 1 && self.x == other.x as i128 && self.y == other.y as i128
+    }
+}
+
+impl PartialEq<RationalPoint> for IntPoint {
+    #[inline]
+    fn eq(&self, other: &RationalPoint) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<IntPoint> for RationalPoint {
+    #[inline]
+    fn partial_cmp(&self, other: &IntPoint) -> Option<Ordering> {
+        Some(self.cmp(&RationalPoint::from(*other)))
+    }
+}
+
+impl PartialOrd<RationalPoint> for IntPoint {
+    #[inline]
+    fn partial_cmp(&self, other: &RationalPoint) -> Option<Ordering> {
+        Some(RationalPoint::from(*self).cmp(other))
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "RationalPoint")]
+struct RawRationalPoint {
+    x: i128,
+    y: i128,
+    den: i128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawRationalPoint> for RationalPoint {
+    type Error = &'static str;
+
+    #[inline]
+    fn try_from(value: RawRationalPoint) -> Result<Self, Self::Error> {
+        RationalPoint::try_new(value.x, value.y, value.den).ok_or("invalid rational point denominator")
+    }
+}
+
+#[inline]
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[inline]
+fn round_half_up(num: i128, den: i128) -> i128 {
+    // floor(num / den + 1/2) for den > 0, the remainder is below den,
+    // so den - rem can not overflow and nothing is doubled
+    let q = num.div_euclid(den);
+    let rem = num.rem_euclid(den);
+    if rem >= den - rem { q + 1 } else { q }
+}
+
+// compares a / b with c / d for b, d > 0 without widening the products
+fn cmp_rational(a: i128, b: i128, c: i128, d: i128) -> Ordering {
+    let qa = a.div_euclid(b);
+    let qc = c.div_euclid(d);
+    if qa != qc {
+        return qa.cmp(&qc);
+    }
+
+    let (mut a, mut b) = (a.rem_euclid(b) as u128, b as u128);
+    let (mut c, mut d) = (c.rem_euclid(d) as u128, d as u128);
+
+    // both fractions are in [0, 1), compare them by their continued fractions
+    loop {
+        match (a == 0, c == 0) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            _ => {}
+        }
+
+        // a / b < c / d <=> d / c < b / a
+        (a, b, c, d) = (d, c, b, a);
+
+        let qa = a / b;
+        let qc = c / d;
+        if qa != qc {
+            return qa.cmp(&qc);
+        }
+        a %= b;
+        c %= d;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::int::point::IntPoint;
+    use crate::int::rational::RationalPoint;
+    use core::cmp::Ordering;
+
+    #[test]
+    fn test_0() {
+        let p = RationalPoint::with_segments(
+            IntPoint::new(0, 0),
+            IntPoint::new(3, 1),
+            IntPoint::new(0, 1),
+            IntPoint::new(3, 0),
+        )
+        .unwrap();
+
+        assert_eq!(p, RationalPoint::new(3, 1, 2));
+        assert_eq!(p.den(), 2);
+        assert!(!p.is_integer());
+        assert_eq!(p.to_int_point(), None);
+        assert_eq!(p.round(), IntPoint::new(2, 1));
+
+        let q = RationalPoint::with_segments(
+            IntPoint::new(-2, -2),
+            IntPoint::new(2, 2),
+            IntPoint::new(-2, 2),
+            IntPoint::new(2, -2),
+        )
+        .unwrap();
+
+        assert!(q.is_integer());
+        assert_eq!(q, IntPoint::new(0, 0));
+        assert_eq!(q.to_int_point(), Some(IntPoint::ZERO));
+    }
+
+    #[test]
+    fn test_1() {
+        let a0 = IntPoint::new(0, 0);
+        let a1 = IntPoint::new(10, 0);
+
+        // parallel
+        assert_eq!(
+            RationalPoint::with_segments(a0, a1, IntPoint::new(0, 1), IntPoint::new(10, 1)),
+            None
+        );
+        // collinear overlap
+        assert_eq!(
+            RationalPoint::with_segments(a0, a1, IntPoint::new(5, 0), IntPoint::new(15, 0)),
+            None
+        );
+        // lines cross outside of the segments
+        assert_eq!(
+            RationalPoint::with_segments(a0, a1, IntPoint::new(11, -1), IntPoint::new(11, 1)),
+            None
+        );
+        // touching at the end
+        assert_eq!(
+            RationalPoint::with_segments(a0, a1, IntPoint::new(10, -1), IntPoint::new(10, 1)),
+            Some(RationalPoint::from(a1))
+        );
+    }
+
+    #[test]
+    fn test_2() {
+        // ties go towards +infinity on both axes
+        assert_eq!(RationalPoint::new(5, -5, 2).round(), IntPoint::new(3, -2));
+        assert_eq!(RationalPoint::new(-7, 7, 2).round(), IntPoint::new(-3, 4));
+        assert_eq!(RationalPoint::new(4, -4, 3).round(), IntPoint::new(1, -1));
+        assert_eq!(RationalPoint::new(5, -5, 3).round(), IntPoint::new(2, -2));
+        assert_eq!(RationalPoint::new(10, 20, -4), RationalPoint::new(-5, -10, 2));
+
+        assert_eq!(RationalPoint::try_new(1, 2, 0), None);
+        assert_eq!(RationalPoint::try_new(i128::MIN, 0, -1), None);
+        let p = RationalPoint::try_new(6, -9, -12).unwrap();
+        assert_eq!((p.x(), p.y(), p.den()), (-2, 3, 4));
+
+        // doubling the numerator or the denominator would overflow i128
+        let big = RationalPoint::new(i128::MAX - 1, i128::MIN + 1, i128::MAX);
+        assert_eq!(big.round(), IntPoint::new(1, -1));
+        let half = RationalPoint::new(i128::MAX / 2 + 1, -(i128::MAX / 2 + 1), i128::MAX);
+        assert_eq!(half.round(), IntPoint::new(1, -1));
+    }
+
+    #[test]
+    fn test_3() {
+        let a0 = IntPoint::new(0, 0);
+        let a1 = IntPoint::new(-7, 5);
+
+        let crossings = [
+            [IntPoint::new(-1, -3), IntPoint::new(-2, 7)],
+            [IntPoint::new(-6, 0), IntPoint::new(-3, 9)],
+            [IntPoint::new(-4, 0), IntPoint::new(-6, 7)],
+            [IntPoint::new(-5, 3), IntPoint::new(0, 3)],
+        ];
+
+        let mut points: alloc::vec::Vec<_> = crossings
+            .iter()
+            .map(|s| RationalPoint::with_segments(a0, a1, s[0], s[1]).unwrap())
+            .collect();
+
+        points.sort_by(|a, b| a.cmp_along(b, a0, a1));
+
+        for w in points.windows(2) {
+            let (x0, _) = w[0].to_f64();
+            let (x1, _) = w[1].to_f64();
+            assert!(x0 > x1);
+            assert_eq!(w[1].cmp_along(&w[0], a1, a0), Ordering::Less);
+        }
+
+        assert!(points[0] > RationalPoint::from(a1));
+        assert!(points[0] < a0);
+        assert!(a0 > points[3]);
+    }
+
+    #[test]
+    fn test_4() {
+        // crossing of the full range diagonals, den is close to 2^65
+        let a0 = IntPoint::new(i32::MIN, i32::MIN);
+        let a1 = IntPoint::new(i32::MAX, i32::MAX);
+        let b0 = IntPoint::new(i32::MIN, i32::MAX);
+        let b1 = IntPoint::new(i32::MAX, i32::MIN + 1);
+
+        let p = RationalPoint::with_segments(a0, a1, b0, b1).unwrap();
+        let (x, y) = p.to_f64();
+        assert!(x.abs() < 1.0 && y.abs() < 1.0);
+        assert_eq!(p.round(), IntPoint::new(0, 0));
+        assert_eq!(p.cmp_x(&RationalPoint::from(IntPoint::ZERO)), x.total_cmp(&0.0));
+
+        // a tiny step along the other diagonal is still ordered exactly
+        let q = RationalPoint::with_segments(a0, a1, b0, IntPoint::new(i32::MAX, i32::MIN)).unwrap();
+        assert_ne!(p, q);
+        assert_eq!(p.cmp_along(&q, a0, a1), p.cmp(&q));
+    }
+
+    #[test]
+    fn test_5() {
+        let mut seed = 0x9E37_79B9_7F4A_7C15_u64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed >> 40) as i128 - (1 << 23)
+        };
+
+        for _ in 0..10_000 {
+            let (a, b, c, d) = (next(), next().abs() + 1, next(), next().abs() + 1);
+            let p = RationalPoint::new(a, 0, b);
+            let q = RationalPoint::new(c, 0, d);
+            assert_eq!(p.cmp_x(&q), (a * d).cmp(&(c * b)));
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::fix_float::FixFloat;
+use crate::fix_vec::FixVec;
+use crate::float::number::FloatNumber;
+use crate::float::point::FloatPoint;
+use crate::float::rect::FloatRect;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+// Float types compare component wise with the tolerance of T.
+//
+// Fixed types take the tolerance in fix units, 1 is 1 / FIX_UNIT. FixFloat is i64 and is already
+// covered by approx itself, so both read the same:
+// assert_abs_diff_eq!(a, b, epsilon = 0.01.fix())
+// assert_abs_diff_eq!(v0, v1, epsilon = 0.01.fix())
+
+impl<T: FloatNumber + AbsDiffEq<Epsilon = T>> AbsDiffEq for FloatPoint<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+    }
+}
+
+impl<T: FloatNumber + RelativeEq<Epsilon = T>> RelativeEq for FloatPoint<T> {
+    #[inline]
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+    }
+}
+
+impl<T: FloatNumber + UlpsEq<Epsilon = T>> UlpsEq for FloatPoint<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.x.ulps_eq(&other.x, epsilon, max_ulps) && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+    }
+}
+
+impl<T: FloatNumber + AbsDiffEq<Epsilon = T>> AbsDiffEq for FloatRect<T> {
+    type Epsilon = T;
+
+    #[inline]
+    fn default_epsilon() -> T {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.min_x.abs_diff_eq(&other.min_x, epsilon)
+            && self.max_x.abs_diff_eq(&other.max_x, epsilon)
+            && self.min_y.abs_diff_eq(&other.min_y, epsilon)
+            && self.max_y.abs_diff_eq(&other.max_y, epsilon)
+    }
+}
+
+impl<T: FloatNumber + RelativeEq<Epsilon = T>> RelativeEq for FloatRect<T> {
+    #[inline]
+    fn default_max_relative() -> T {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+        self.min_x.relative_eq(&other.min_x, epsilon, max_relative)
+            && self.max_x.relative_eq(&other.max_x, epsilon, max_relative)
+            && self.min_y.relative_eq(&other.min_y, epsilon, max_relative)
+            && self.max_y.relative_eq(&other.max_y, epsilon, max_relative)
+    }
+}
+
+impl<T: FloatNumber + UlpsEq<Epsilon = T>> UlpsEq for FloatRect<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+        self.min_x.ulps_eq(&other.min_x, epsilon, max_ulps)
+            && self.max_x.ulps_eq(&other.max_x, epsilon, max_ulps)
+            && self.min_y.ulps_eq(&other.min_y, epsilon, max_ulps)
+            && self.max_y.ulps_eq(&other.max_y, epsilon, max_ulps)
+    }
+}
+
+// The default of one fix unit absorbs the rounding of a single fix operation.
+// abs_diff keeps the check exact for coordinates far apart, where x - y would overflow.
+impl AbsDiffEq for FixVec {
+    type Epsilon = FixFloat;
+
+    #[inline]
+    fn default_epsilon() -> FixFloat {
+        1
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: FixFloat) -> bool {
+        if epsilon < 0 {
+            return false;
+        }
+        let epsilon = epsilon as u64;
+        self.x.abs_diff(other.x) <= epsilon && self.y.abs_diff(other.y) <= epsilon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_float::{FixConvert, FixMath};
+    use crate::fix_vec::FixVec;
+    use crate::float::point::FloatPoint;
+    use crate::float::rect::FloatRect;
+    use approx::{
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne, assert_ulps_eq,
+    };
+
+    #[test]
+    fn test_0() {
+        let a = FloatPoint::new(0.1 + 0.2, 1.0);
+        let b = FloatPoint::new(0.3, 1.0);
+        assert_ne!(a, b);
+        assert_abs_diff_eq!(a, b);
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+        assert_abs_diff_ne!(a, FloatPoint::new(0.3, 1.001));
+        assert_abs_diff_eq!(a, FloatPoint::new(0.3, 1.001), epsilon = 0.01);
+
+        let big = FloatPoint::new(1.0e9f32, -1.0e9);
+        assert_relative_eq!(big, FloatPoint::new(1.0e9 + 64.0, -1.0e9), max_relative = 1.0e-6);
+        assert_relative_ne!(big, FloatPoint::new(1.1e9, -1.0e9));
+    }
+
+    #[test]
+    fn test_1() {
+        let r0 = FloatRect::new(-1.0, 1.0, 0.1 * 3.0, 2.0);
+        let r1 = FloatRect::new(-1.0, 1.0, 0.3, 2.0);
+        assert_abs_diff_eq!(r0, r1);
+        assert_ulps_eq!(r0, r1);
+        assert_abs_diff_ne!(r0, FloatRect::new(-1.0, 1.0, 0.3, 2.1));
+    }
+
+    #[test]
+    fn test_2() {
+        // 1 / 3 is 341.33 fix units, it can only be matched within one unit
+        let third = 1.fix().fix_div(3.fix());
+        assert_ne!(third, 0.334.fix());
+        assert_abs_diff_eq!(third, 0.334.fix(), epsilon = 1);
+
+        let v = FixVec::new(third, -third);
+        assert_abs_diff_eq!(v, FixVec::new_f64(0.333, -0.333));
+        assert_abs_diff_eq!(v, FixVec::new_f64(0.3, -0.3), epsilon = 0.05.fix());
+        assert_abs_diff_ne!(v, FixVec::new_f64(0.3, -0.3));
+
+        // far apart values do not overflow
+        assert_abs_diff_ne!(
+            FixVec::new(i64::MIN, 0),
+            FixVec::new(i64::MAX, 0),
+            epsilon = i64::MAX
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::fix_vec::FixVec;
+use crate::float::number::FloatNumber;
+use crate::float::point::FloatPoint;
+use crate::float::rect::FloatRect;
+use crate::i128::Int128;
+use crate::int::point::IntPoint;
+use crate::int::point64::IntPoint64;
+use crate::int::rect::IntRect;
+use crate::u128::UInt128;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+// A quarter of the values come from the edge lists below, the raw byte mapping of
+// arbitrary almost never hits i32::MIN or IntPoint::EMPTY on its own.
+
+const I32_EDGES: [i32; 7] = [i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX - 1, i32::MAX];
+const I64_EDGES: [i64; 9] = [
+    i64::MIN,
+    i64::MIN + 1,
+    i32::MIN as i64,
+    -1,
+    0,
+    1,
+    i32::MAX as i64,
+    i64::MAX - 1,
+    i64::MAX,
+];
+
+#[inline]
+fn biased<'a, T: Copy + Arbitrary<'a>>(u: &mut Unstructured<'a>, edges: &[T]) -> Result<T> {
+    if u.ratio(1u8, 4u8)? {
+        Ok(*u.choose(edges)?)
+    } else {
+        T::arbitrary(u)
+    }
+}
+
+#[inline]
+fn biased_float<'a, T: FloatNumber + Arbitrary<'a>>(u: &mut Unstructured<'a>) -> Result<T> {
+    if u.ratio(1u8, 4u8)? {
+        let edges = [0.0, -0.0, 1.0, -1.0, f64::MIN_POSITIVE, f64::MAX, f64::MIN];
+        Ok(T::from_float(*u.choose(&edges)?))
+    } else {
+        T::arbitrary(u)
+    }
+}
+
+// sorted pair, with a 1/8 chance of a zero extent
+#[inline]
+fn extent<'a>(u: &mut Unstructured<'a>) -> Result<(i32, i32)> {
+    let a = biased(u, &I32_EDGES)?;
+    if u.ratio(1u8, 8u8)? {
+        return Ok((a, a));
+    }
+    let b = biased(u, &I32_EDGES)?;
+    Ok((a.min(b), a.max(b)))
+}
+
+impl<'a> Arbitrary<'a> for IntPoint {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1u8, 16u8)? {
+            return Ok(IntPoint::EMPTY);
+        }
+        Ok(IntPoint::new(biased(u, &I32_EDGES)?, biased(u, &I32_EDGES)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for IntPoint64 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(IntPoint64::new(biased(u, &I64_EDGES)?, biased(u, &I64_EDGES)?))
+    }
+}
+
+// always a valid rect: min <= max on both axes
+impl<'a> Arbitrary<'a> for IntRect {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (min_x, max_x) = extent(u)?;
+        let (min_y, max_y) = extent(u)?;
+        Ok(IntRect::new(min_x, max_x, min_y, max_y))
+    }
+}
+
+impl<'a> Arbitrary<'a> for FixVec {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(FixVec::new(biased(u, &I64_EDGES)?, biased(u, &I64_EDGES)?))
+    }
+}
+
+// any bits, NaN and infinities included
+impl<'a, T: FloatNumber + Arbitrary<'a>> Arbitrary<'a> for FloatPoint<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(FloatPoint::new(biased_float(u)?, biased_float(u)?))
+    }
+}
+
+// min <= max holds unless a bound is NaN
+impl<'a, T: FloatNumber + Arbitrary<'a>> Arbitrary<'a> for FloatRect<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let x0: T = biased_float(u)?;
+        let x1: T = biased_float(u)?;
+        let y0: T = biased_float(u)?;
+        let y1: T = biased_float(u)?;
+        let (min_x, max_x) = if x1 < x0 { (x1, x0) } else { (x0, x1) };
+        let (min_y, max_y) = if y1 < y0 { (y1, y0) } else { (y0, y1) };
+        Ok(FloatRect::new(min_x, max_x, min_y, max_y))
+    }
+}
+
+impl<'a> Arbitrary<'a> for UInt128 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let edges = [0, 1, u64::MAX];
+        Ok(UInt128::new(biased(u, &edges)?, biased(u, &edges)?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Int128 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Int128::new(biased(u, &I64_EDGES)?, biased(u, &[0, 1, u64::MAX])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_vec::FixVec;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use crate::int::rect::IntRect;
+    use alloc::vec::Vec;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    fn noise(len: usize) -> Vec<u8> {
+        let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+        (0..len)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_0() {
+        let bytes = noise(1 << 16);
+        let mut u = Unstructured::new(&bytes);
+
+        let mut min_count = 0;
+        let mut empty_count = 0;
+        let mut degenerate_count = 0;
+        for _ in 0..1000 {
+            let p = IntPoint::arbitrary(&mut u).unwrap();
+            if p.x == i32::MIN {
+                min_count += 1;
+            }
+            if p == IntPoint::EMPTY {
+                empty_count += 1;
+            }
+
+            let r = IntRect::arbitrary(&mut u).unwrap();
+            assert!(r.min_x <= r.max_x && r.min_y <= r.max_y);
+            if r.min_x == r.max_x || r.min_y == r.max_y {
+                degenerate_count += 1;
+            }
+
+            let f = FloatRect::<f64>::arbitrary(&mut u).unwrap();
+            assert!(f.max_x.partial_cmp(&f.min_x) != Some(core::cmp::Ordering::Less));
+            assert!(f.max_y.partial_cmp(&f.min_y) != Some(core::cmp::Ordering::Less));
+
+            FixVec::arbitrary(&mut u).unwrap();
+        }
+
+        assert!(min_count > 10);
+        assert!(empty_count > 10);
+        assert!(degenerate_count > 50);
+    }
+}
@@ -1,6 +1,9 @@
+use crate::fix_vec::FixVec;
 use crate::int::point::IntPoint;
+use crate::int::rect::IntRect;
 use i_key_sort::bin_key::index::{BinKey, BinLayout};
 
+// keyed on x
 impl BinKey<i32> for IntPoint {
     #[inline(always)]
     fn bin_key(&self) -> i32 {
@@ -12,3 +15,135 @@ impl BinKey<i32> for IntPoint {
         layout.index(self.x)
     }
 }
+
+// IntPoint keyed on (x, y), the key order is the same as the IntPoint order
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntPointByXY(pub IntPoint);
+
+impl BinKey<i64> for IntPointByXY {
+    #[inline(always)]
+    fn bin_key(&self) -> i64 {
+        xy_key(self.0.x, self.0.y)
+    }
+
+    #[inline(always)]
+    fn bin_index(&self, layout: &BinLayout<i64>) -> usize {
+        layout.index(xy_key(self.0.x, self.0.y))
+    }
+}
+
+// IntPoint keyed on y, for sweeps along the y axis
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntPointByY(pub IntPoint);
+
+impl BinKey<i32> for IntPointByY {
+    #[inline(always)]
+    fn bin_key(&self) -> i32 {
+        self.0.y
+    }
+
+    #[inline(always)]
+    fn bin_index(&self, layout: &BinLayout<i32>) -> usize {
+        layout.index(self.0.y)
+    }
+}
+
+// keyed on x
+impl BinKey<i64> for FixVec {
+    #[inline(always)]
+    fn bin_key(&self) -> i64 {
+        self.x
+    }
+
+    #[inline(always)]
+    fn bin_index(&self, layout: &BinLayout<i64>) -> usize {
+        layout.index(self.x)
+    }
+}
+
+// keyed on min_x
+impl BinKey<i32> for IntRect {
+    #[inline(always)]
+    fn bin_key(&self) -> i32 {
+        self.min_x
+    }
+
+    #[inline(always)]
+    fn bin_index(&self, layout: &BinLayout<i32>) -> usize {
+        layout.index(self.min_x)
+    }
+}
+
+#[inline(always)]
+fn xy_key(x: i32, y: i32) -> i64 {
+    // flipping the sign bit maps y to an unsigned value with the same order
+    ((x as i64) << 32) | ((y as u32) ^ 0x8000_0000) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_vec::FixVec;
+    use crate::int::point::IntPoint;
+    use crate::int::rect::IntRect;
+    use crate::integration::bin_key::{IntPointByXY, IntPointByY};
+    use i_key_sort::bin_key::index::{BinKey, BinLayout};
+
+    #[test]
+    fn test_0() {
+        let points = [
+            IntPoint::new(i32::MIN, i32::MAX),
+            IntPoint::new(-1, i32::MIN),
+            IntPoint::new(-1, -1),
+            IntPoint::new(-1, 0),
+            IntPoint::new(0, i32::MIN),
+            IntPoint::new(0, 5),
+            IntPoint::new(i32::MAX, i32::MIN),
+            IntPoint::new(i32::MAX, i32::MAX),
+        ];
+
+        for w in points.windows(2) {
+            assert!(IntPointByXY(w[0]).bin_key() < IntPointByXY(w[1]).bin_key());
+            assert!(w[0].bin_key() <= w[1].bin_key());
+        }
+    }
+
+    #[test]
+    fn test_1() {
+        let points = [
+            IntPoint::new(7, -100),
+            IntPoint::new(-3, -20),
+            IntPoint::new(0, 0),
+            IntPoint::new(-9, 40),
+            IntPoint::new(1, 100),
+        ];
+
+        let layout = BinLayout::new(-100..100, 64).unwrap();
+        let mut last = 0;
+        for p in points.iter() {
+            let index = IntPointByY(*p).bin_index(&layout);
+            assert!(index >= last);
+            assert!(index < layout.count());
+            last = index;
+        }
+
+        let layout = BinLayout::new(-10..10, 64).unwrap();
+        assert!(points[3].bin_index(&layout) < points[1].bin_index(&layout));
+    }
+
+    #[test]
+    fn test_2() {
+        let a = FixVec::new(-1 << 40, 3);
+        let b = FixVec::new(1 << 40, -3);
+        let layout = BinLayout::new(a.x..b.x, 64).unwrap();
+        assert_eq!(a.bin_index(&layout), 0);
+        assert_eq!(b.bin_index(&layout), layout.count() - 1);
+
+        let r0 = IntRect::new(-5, 10, 0, 1);
+        let r1 = IntRect::new(3, 4, -7, 7);
+        assert_eq!(r0.bin_key(), -5);
+        let layout = BinLayout::new(-5..3, 64).unwrap();
+        assert!(r0.bin_index(&layout) < r1.bin_index(&layout));
+    }
+}
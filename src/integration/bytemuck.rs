@@ -0,0 +1,78 @@
+use crate::float::{point::FloatPoint, rect::FloatRect};
+
+// The derive can not see through the generic T, so the float types are implemented
+// for f32 and f64 only. Both are repr(C) with fields of a single type, so there is no padding.
+
+// SAFETY: repr(C) struct of two f32, every bit pattern is valid, zero is 0.0
+unsafe impl bytemuck::Zeroable for FloatPoint<f32> {}
+// SAFETY: see above, no padding and no invalid values
+unsafe impl bytemuck::Pod for FloatPoint<f32> {}
+
+// SAFETY: repr(C) struct of two f64, every bit pattern is valid, zero is 0.0
+unsafe impl bytemuck::Zeroable for FloatPoint<f64> {}
+// SAFETY: see above, no padding and no invalid values
+unsafe impl bytemuck::Pod for FloatPoint<f64> {}
+
+// SAFETY: repr(C) struct of four f32, every bit pattern is valid, zero is 0.0
+unsafe impl bytemuck::Zeroable for FloatRect<f32> {}
+// SAFETY: see above, no padding and no invalid values
+unsafe impl bytemuck::Pod for FloatRect<f32> {}
+
+// SAFETY: repr(C) struct of four f64, every bit pattern is valid, zero is 0.0
+unsafe impl bytemuck::Zeroable for FloatRect<f64> {}
+// SAFETY: see above, no padding and no invalid values
+unsafe impl bytemuck::Pod for FloatRect<f64> {}
+
+const _: () = {
+    use core::mem::size_of;
+    assert!(size_of::<FloatPoint<f32>>() == 8);
+    assert!(size_of::<FloatPoint<f64>>() == 16);
+    assert!(size_of::<FloatRect<f32>>() == 16);
+    assert!(size_of::<FloatRect<f64>>() == 32);
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_vec::FixVec;
+    use crate::float::point::FloatPoint;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use crate::int::rect::IntRect;
+
+    #[test]
+    fn test_0() {
+        // x, y pairs as they come from a gpu buffer
+        let raw: [i32; 6] = [1, -2, 3, -4, i32::MAX, i32::MIN];
+        let points: &[IntPoint] = bytemuck::cast_slice(&raw);
+        assert_eq!(
+            points,
+            &[
+                IntPoint::new(1, -2),
+                IntPoint::new(3, -4),
+                IntPoint::new(i32::MAX, i32::MIN)
+            ]
+        );
+
+        let back: &[i32] = bytemuck::cast_slice(points);
+        assert_eq!(back, &raw);
+
+        let rect: IntRect = bytemuck::cast([-1, 1, -2, 2]);
+        assert_eq!((rect.min_x, rect.max_x, rect.min_y, rect.max_y), (-1, 1, -2, 2));
+
+        let v: FixVec = bytemuck::cast([7i64, -9]);
+        assert_eq!(v, FixVec::new(7, -9));
+    }
+
+    #[test]
+    fn test_1() {
+        let raw: [f32; 4] = [0.5, -1.5, 2.25, 3.0];
+        let points: &[FloatPoint<f32>] = bytemuck::cast_slice(&raw);
+        assert_eq!((points[1].x, points[1].y), (2.25, 3.0));
+
+        let rect: FloatRect<f64> = bytemuck::cast([-1.0f64, 1.0, -2.0, 2.0]);
+        assert_eq!(rect, FloatRect::new(-1.0, 1.0, -2.0, 2.0));
+
+        let zero: FloatPoint<f64> = bytemuck::Zeroable::zeroed();
+        assert_eq!((zero.x, zero.y), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    float::{compatible::FloatPointCompatible, number::FloatNumber, point::FloatPoint, rect::FloatRect},
+    int::point::IntPoint,
+};
+use euclid::{Box2D, Point2D, Rect, Size2D, Vector2D};
+
+// The unit tag U is carried through every conversion, so a FloatPointAdapter<Point2D<T, U>, T>
+// gives back points in the same space they came from.
+
+// euclid::Point2D / euclid::Vector2D for f32 and f64
+impl<T: FloatNumber, U> FloatPointCompatible<T> for Point2D<T, U> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Point2D::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber, U> FloatPointCompatible<T> for Vector2D<T, U> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Vector2D::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber, U> From<FloatPoint<T>> for Point2D<T, U> {
+    #[inline(always)]
+    fn from(point: FloatPoint<T>) -> Self {
+        Point2D::new(point.x, point.y)
+    }
+}
+
+impl<T: FloatNumber, U> From<Point2D<T, U>> for FloatPoint<T> {
+    #[inline(always)]
+    fn from(point: Point2D<T, U>) -> Self {
+        FloatPoint::new(point.x, point.y)
+    }
+}
+
+// Point2D<i32, U> / IntPoint
+impl<U> From<IntPoint> for Point2D<i32, U> {
+    #[inline(always)]
+    fn from(point: IntPoint) -> Self {
+        Point2D::new(point.x, point.y)
+    }
+}
+
+impl<U> From<Point2D<i32, U>> for IntPoint {
+    #[inline(always)]
+    fn from(point: Point2D<i32, U>) -> Self {
+        IntPoint::new(point.x, point.y)
+    }
+}
+
+// Box2D / Rect
+impl<T: FloatNumber, U> From<FloatRect<T>> for Box2D<T, U> {
+    #[inline(always)]
+    fn from(rect: FloatRect<T>) -> Self {
+        Box2D::new(
+            Point2D::new(rect.min_x, rect.min_y),
+            Point2D::new(rect.max_x, rect.max_y),
+        )
+    }
+}
+
+impl<T: FloatNumber, U> From<Box2D<T, U>> for FloatRect<T> {
+    #[inline(always)]
+    fn from(rect: Box2D<T, U>) -> Self {
+        FloatRect::new(rect.min.x, rect.max.x, rect.min.y, rect.max.y)
+    }
+}
+
+impl<T: FloatNumber, U> From<FloatRect<T>> for Rect<T, U> {
+    #[inline(always)]
+    fn from(rect: FloatRect<T>) -> Self {
+        Rect::new(
+            Point2D::new(rect.min_x, rect.min_y),
+            Size2D::new(rect.width(), rect.height()),
+        )
+    }
+}
+
+impl<T: FloatNumber, U> From<Rect<T, U>> for FloatRect<T> {
+    #[inline(always)]
+    fn from(rect: Rect<T, U>) -> Self {
+        let min = rect.origin;
+        FloatRect::new(min.x, min.x + rect.size.width, min.y, min.y + rect.size.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::FloatPointAdapter;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use euclid::{Box2D, Point2D, Rect};
+
+    struct WorldSpace;
+
+    type WorldPoint = Point2D<f64, WorldSpace>;
+
+    #[test]
+    fn test_0() {
+        let points = [WorldPoint::new(-2.0, -4.0), WorldPoint::new(5.0, 3.0)];
+        let adapter = FloatPointAdapter::<WorldPoint, f64>::with_iter(points.iter());
+
+        let p: IntPoint = adapter.float_to_int(&points[1]);
+        // the unit type survives the round trip
+        let f: WorldPoint = adapter.int_to_float(&p);
+        assert_eq!(f, points[1]);
+
+        let q: Point2D<i32, WorldSpace> = p.into();
+        assert_eq!(IntPoint::from(q), p);
+    }
+
+    #[test]
+    fn test_1() {
+        let rect = FloatRect::new(-1.0f32, 3.0, 2.0, 6.0);
+
+        let b: Box2D<f32, WorldSpace> = rect.into();
+        assert_eq!(FloatRect::from(b), rect);
+
+        let r: Rect<f32, WorldSpace> = rect.into();
+        assert_eq!(r.size.width, 4.0);
+        assert_eq!(FloatRect::from(r), rect);
+    }
+}
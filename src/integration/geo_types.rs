@@ -0,0 +1,245 @@
+use crate::{
+    adapter::FloatPointAdapter,
+    float::{compatible::FloatPointCompatible, number::FloatNumber, rect::FloatRect},
+    int::point::IntPoint,
+};
+use alloc::vec::Vec;
+use geo_types::{
+    Coord, CoordNum, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+// geo_types::Coord for f32 and f64
+impl<T: FloatNumber + CoordNum> FloatPointCompatible<T> for Coord<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Coord { x, y }
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber + CoordNum> From<FloatRect<T>> for Rect<T> {
+    #[inline(always)]
+    fn from(rect: FloatRect<T>) -> Self {
+        Rect::new(
+            Coord {
+                x: rect.min_x,
+                y: rect.min_y,
+            },
+            Coord {
+                x: rect.max_x,
+                y: rect.max_y,
+            },
+        )
+    }
+}
+
+impl<T: FloatNumber + CoordNum> From<Rect<T>> for FloatRect<T> {
+    #[inline(always)]
+    fn from(rect: Rect<T>) -> Self {
+        let (min, max) = (rect.min(), rect.max());
+        FloatRect::new(min.x, max.x, min.y, max.y)
+    }
+}
+
+// visits every coordinate of a geometry, geo-types itself has no coords iterator
+pub trait GeoCoords<T: CoordNum> {
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F);
+}
+
+impl<T: CoordNum> GeoCoords<T> for Coord<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        f(*self)
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Point<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        f(self.0)
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Line<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        f(self.start);
+        f(self.end);
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for LineString<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.0.iter().for_each(|c| f(*c));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Polygon<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.exterior().for_each_coord(f);
+        self.interiors().iter().for_each(|ring| ring.for_each_coord(f));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for MultiPoint<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.0.iter().for_each(|p| f(p.0));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for MultiLineString<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.0.iter().for_each(|line| line.for_each_coord(f));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for MultiPolygon<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.0.iter().for_each(|polygon| polygon.for_each_coord(f));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Rect<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        f(self.min());
+        f(self.max());
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Triangle<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        f(self.v1());
+        f(self.v2());
+        f(self.v3());
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for GeometryCollection<T> {
+    #[inline]
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        self.0.iter().for_each(|geometry| geometry.for_each_coord(f));
+    }
+}
+
+impl<T: CoordNum> GeoCoords<T> for Geometry<T> {
+    fn for_each_coord<F: FnMut(Coord<T>)>(&self, f: &mut F) {
+        match self {
+            Geometry::Point(g) => g.for_each_coord(f),
+            Geometry::Line(g) => g.for_each_coord(f),
+            Geometry::LineString(g) => g.for_each_coord(f),
+            Geometry::Polygon(g) => g.for_each_coord(f),
+            Geometry::MultiPoint(g) => g.for_each_coord(f),
+            Geometry::MultiLineString(g) => g.for_each_coord(f),
+            Geometry::MultiPolygon(g) => g.for_each_coord(f),
+            Geometry::GeometryCollection(g) => g.for_each_coord(f),
+            Geometry::Rect(g) => g.for_each_coord(f),
+            Geometry::Triangle(g) => g.for_each_coord(f),
+        }
+    }
+}
+
+impl<T: FloatNumber + CoordNum> FloatRect<T> {
+    // non-finite coordinates are skipped the same way as in with_iter
+    #[inline]
+    pub fn with_geometry<G: GeoCoords<T>>(geometry: &G) -> Option<Self> {
+        let mut rect: Option<Self> = None;
+        geometry.for_each_coord(&mut |c| {
+            if !(c.x.is_finite() && c.y.is_finite()) {
+                return;
+            }
+            match rect.as_mut() {
+                Some(rect) => rect.unsafe_add_point(&c),
+                None => rect = Some(FloatRect::with_point(c)),
+            }
+        });
+        rect
+    }
+}
+
+impl<P: FloatPointCompatible<T>, T: FloatNumber + CoordNum> FloatPointAdapter<P, T> {
+    #[inline]
+    pub fn with_geometry<G: GeoCoords<T>>(geometry: &G) -> Self {
+        Self::new(FloatRect::with_geometry(geometry).unwrap_or(FloatRect::zero()))
+    }
+
+    #[inline]
+    pub fn float_to_int_line_string(&self, line: &LineString<T>) -> Vec<IntPoint> {
+        line.0
+            .iter()
+            .map(|c| self.float_to_int(&P::from_xy(c.x, c.y)))
+            .collect()
+    }
+
+    #[inline]
+    pub fn int_to_float_line_string(&self, points: &[IntPoint]) -> LineString<T> {
+        points
+            .iter()
+            .map(|p| {
+                let f = self.int_to_float(p);
+                Coord { x: f.x(), y: f.y() }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::FloatPointAdapter;
+    use crate::float::rect::FloatRect;
+    use alloc::vec;
+    use geo_types::{Coord, Geometry, GeometryCollection, LineString, Point, Rect, polygon};
+
+    #[test]
+    fn test_0() {
+        let rect = Rect::new(Coord { x: 3.0, y: -1.0 }, Coord { x: -2.0, y: 4.0 });
+        let float_rect = FloatRect::from(rect);
+        assert_eq!(float_rect, FloatRect::new(-2.0, 3.0, -1.0, 4.0));
+        assert_eq!(Rect::from(float_rect), rect);
+    }
+
+    #[test]
+    fn test_1() {
+        let polygon = polygon![
+            exterior: [(x: 0.0, y: 0.0), (x: 10.0, y: 0.0), (x: 10.0, y: 10.0), (x: 0.0, y: 10.0)],
+            interiors: [[(x: 2.0, y: 2.0), (x: 4.0, y: 2.0), (x: 4.0, y: 4.0)]],
+        ];
+        let collection = GeometryCollection(vec![
+            Geometry::Polygon(polygon),
+            Geometry::Point(Point::new(-5.0, 12.0)),
+            Geometry::Point(Point::new(f64::NAN, 100.0)),
+        ]);
+
+        let rect = FloatRect::with_geometry(&collection).unwrap();
+        assert_eq!(rect, FloatRect::new(-5.0, 10.0, 0.0, 12.0));
+
+        let adapter = FloatPointAdapter::<Coord<f64>, f64>::with_geometry(&collection);
+        assert_eq!(adapter.rect, rect);
+    }
+
+    #[test]
+    fn test_2() {
+        let line: LineString<f64> = vec![(0.0, 0.0), (1.5, 2.25), (-3.0, 0.75)].into();
+        let adapter = FloatPointAdapter::<Coord<f64>, f64>::with_geometry(&line);
+
+        let points = adapter.float_to_int_line_string(&line);
+        assert_eq!(points.len(), 3);
+        assert_eq!(adapter.int_to_float_line_string(&points), line);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    float::{compatible::FloatPointCompatible, point::FloatPoint, rect::FloatRect},
+    int::point::IntPoint,
+};
+
+// kurbo::Point / kurbo::Vec2, kurbo works in f64 only
+impl FloatPointCompatible<f64> for kurbo::Point {
+    #[inline(always)]
+    fn from_xy(x: f64, y: f64) -> Self {
+        kurbo::Point::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+impl FloatPointCompatible<f64> for kurbo::Vec2 {
+    #[inline(always)]
+    fn from_xy(x: f64, y: f64) -> Self {
+        kurbo::Vec2::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+impl From<FloatPoint<f64>> for kurbo::Point {
+    #[inline(always)]
+    fn from(point: FloatPoint<f64>) -> Self {
+        kurbo::Point::new(point.x, point.y)
+    }
+}
+
+impl From<kurbo::Point> for FloatPoint<f64> {
+    #[inline(always)]
+    fn from(point: kurbo::Point) -> Self {
+        FloatPoint::new(point.x, point.y)
+    }
+}
+
+impl From<IntPoint> for kurbo::Point {
+    #[inline(always)]
+    fn from(point: IntPoint) -> Self {
+        kurbo::Point::new(point.x as f64, point.y as f64)
+    }
+}
+
+impl From<FloatRect<f64>> for kurbo::Rect {
+    #[inline(always)]
+    fn from(rect: FloatRect<f64>) -> Self {
+        kurbo::Rect::new(rect.min_x, rect.min_y, rect.max_x, rect.max_y)
+    }
+}
+
+impl From<kurbo::Rect> for FloatRect<f64> {
+    #[inline(always)]
+    fn from(rect: kurbo::Rect) -> Self {
+        // kurbo does not keep x0 <= x1
+        FloatRect::new(rect.min_x(), rect.max_x(), rect.min_y(), rect.max_y())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::FloatPointAdapter;
+    use crate::float::rect::FloatRect;
+
+    #[test]
+    fn test_0() {
+        let points = [kurbo::Point::new(-2.0, -4.0), kurbo::Point::new(5.0, 3.0)];
+        let adapter = FloatPointAdapter::<kurbo::Point, f64>::with_iter(points.iter());
+
+        let p = adapter.float_to_int(&points[0]);
+        assert_eq!(adapter.int_to_float(&p), points[0]);
+
+        let rect = kurbo::Rect::new(5.0, 3.0, -2.0, -4.0);
+        assert_eq!(FloatRect::from(rect), adapter.rect);
+        assert_eq!(kurbo::Rect::from(adapter.rect), rect.abs());
+    }
+}
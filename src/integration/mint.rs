@@ -0,0 +1,169 @@
+use crate::{
+    fix_vec::FixVec,
+    float::{compatible::FloatPointCompatible, number::FloatNumber, point::FloatPoint},
+    int::point::IntPoint,
+};
+
+// mint::Point2 / mint::Vector2 for f32 and f64
+impl<T: FloatNumber> FloatPointCompatible<T> for mint::Point2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        mint::Point2 { x, y }
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber> FloatPointCompatible<T> for mint::Vector2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        mint::Vector2 { x, y }
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+// FloatPoint
+impl<T: FloatNumber> From<FloatPoint<T>> for mint::Point2<T> {
+    #[inline(always)]
+    fn from(point: FloatPoint<T>) -> Self {
+        mint::Point2 {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl<T: FloatNumber> From<mint::Point2<T>> for FloatPoint<T> {
+    #[inline(always)]
+    fn from(point: mint::Point2<T>) -> Self {
+        FloatPoint::new(point.x, point.y)
+    }
+}
+
+impl<T: FloatNumber> From<FloatPoint<T>> for mint::Vector2<T> {
+    #[inline(always)]
+    fn from(point: FloatPoint<T>) -> Self {
+        mint::Vector2 {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl<T: FloatNumber> From<mint::Vector2<T>> for FloatPoint<T> {
+    #[inline(always)]
+    fn from(point: mint::Vector2<T>) -> Self {
+        FloatPoint::new(point.x, point.y)
+    }
+}
+
+// IntPoint / i32
+impl From<IntPoint> for mint::Point2<i32> {
+    #[inline(always)]
+    fn from(point: IntPoint) -> Self {
+        mint::Point2 {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<mint::Point2<i32>> for IntPoint {
+    #[inline(always)]
+    fn from(point: mint::Point2<i32>) -> Self {
+        IntPoint::new(point.x, point.y)
+    }
+}
+
+impl From<IntPoint> for mint::Vector2<i32> {
+    #[inline(always)]
+    fn from(point: IntPoint) -> Self {
+        mint::Vector2 {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
+impl From<mint::Vector2<i32>> for IntPoint {
+    #[inline(always)]
+    fn from(point: mint::Vector2<i32>) -> Self {
+        IntPoint::new(point.x, point.y)
+    }
+}
+
+// FixVec / i64
+impl From<FixVec> for mint::Point2<i64> {
+    #[inline(always)]
+    fn from(vec: FixVec) -> Self {
+        mint::Point2 { x: vec.x, y: vec.y }
+    }
+}
+
+impl From<mint::Point2<i64>> for FixVec {
+    #[inline(always)]
+    fn from(point: mint::Point2<i64>) -> Self {
+        FixVec::new(point.x, point.y)
+    }
+}
+
+impl From<FixVec> for mint::Vector2<i64> {
+    #[inline(always)]
+    fn from(vec: FixVec) -> Self {
+        mint::Vector2 { x: vec.x, y: vec.y }
+    }
+}
+
+impl From<mint::Vector2<i64>> for FixVec {
+    #[inline(always)]
+    fn from(vec: mint::Vector2<i64>) -> Self {
+        FixVec::new(vec.x, vec.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::FloatPointAdapter;
+    use crate::fix_vec::FixVec;
+    use crate::float::point::FloatPoint;
+    use crate::int::point::IntPoint;
+
+    #[test]
+    fn test_0() {
+        let points = [mint::Point2 { x: -2.0, y: -4.0 }, mint::Point2 { x: 5.0, y: 3.0 }];
+        let adapter = FloatPointAdapter::<mint::Point2<f64>, f64>::with_iter(points.iter());
+
+        let p = adapter.float_to_int(&points[1]);
+        let f = adapter.int_to_float(&p);
+        assert_eq!(f, points[1]);
+
+        let v: mint::Vector2<f32> = FloatPoint::new(1.5f32, -2.5).into();
+        assert_eq!(FloatPoint::from(v).y, -2.5);
+    }
+
+    #[test]
+    fn test_1() {
+        let p: mint::Point2<i32> = IntPoint::new(3, -7).into();
+        assert_eq!(IntPoint::from(p), IntPoint::new(3, -7));
+
+        let v: mint::Vector2<i64> = FixVec::new(i64::MIN, i64::MAX).into();
+        assert_eq!(FixVec::from(v), FixVec::new(i64::MIN, i64::MAX));
+    }
+}
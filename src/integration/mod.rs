@@ -1,2 +1,24 @@
+#[cfg(feature = "approx")]
+pub mod approx;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "i_key_sort")]
+pub mod bin_key;
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+#[cfg(feature = "euclid")]
+pub mod euclid;
+#[cfg(feature = "geo-types")]
+pub mod geo_types;
 #[cfg(feature = "glam")]
 pub mod glam;
+#[cfg(feature = "kurbo")]
+pub mod kurbo;
+#[cfg(feature = "mint")]
+pub mod mint;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
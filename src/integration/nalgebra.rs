@@ -0,0 +1,137 @@
+use crate::{
+    float::{compatible::FloatPointCompatible, number::FloatNumber, point::FloatPoint, rect::FloatRect},
+    int::point::IntPoint,
+};
+use nalgebra::{Point2, Scalar, Vector2};
+
+// nalgebra::Point2 / nalgebra::Vector2 for f32 and f64
+impl<T: FloatNumber + Scalar> FloatPointCompatible<T> for Point2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Point2::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber + Scalar> FloatPointCompatible<T> for Vector2<T> {
+    #[inline(always)]
+    fn from_xy(x: T, y: T) -> Self {
+        Vector2::new(x, y)
+    }
+
+    #[inline(always)]
+    fn x(&self) -> T {
+        self.x
+    }
+
+    #[inline(always)]
+    fn y(&self) -> T {
+        self.y
+    }
+}
+
+impl<T: FloatNumber + Scalar> From<FloatPoint<T>> for Point2<T> {
+    #[inline(always)]
+    fn from(point: FloatPoint<T>) -> Self {
+        Point2::new(point.x, point.y)
+    }
+}
+
+impl<T: FloatNumber + Scalar> From<Point2<T>> for FloatPoint<T> {
+    #[inline(always)]
+    fn from(point: Point2<T>) -> Self {
+        FloatPoint::new(point.x, point.y)
+    }
+}
+
+// Point2<i32> / IntPoint
+impl From<IntPoint> for Point2<i32> {
+    #[inline(always)]
+    fn from(point: IntPoint) -> Self {
+        Point2::new(point.x, point.y)
+    }
+}
+
+impl From<Point2<i32>> for IntPoint {
+    #[inline(always)]
+    fn from(point: Point2<i32>) -> Self {
+        IntPoint::new(point.x, point.y)
+    }
+}
+
+// AABB as a (mins, maxs) pair, the same layout as parry's Aabb.
+// The tuple is positional, the first point must be the min corner and the second the max one.
+impl<T: FloatNumber + Scalar> From<FloatRect<T>> for (Point2<T>, Point2<T>) {
+    #[inline(always)]
+    fn from(rect: FloatRect<T>) -> Self {
+        (
+            Point2::new(rect.min_x, rect.min_y),
+            Point2::new(rect.max_x, rect.max_y),
+        )
+    }
+}
+
+impl<T: FloatNumber + Scalar> From<(Point2<T>, Point2<T>)> for FloatRect<T> {
+    #[inline(always)]
+    fn from(aabb: (Point2<T>, Point2<T>)) -> Self {
+        let (mins, maxs) = aabb;
+        debug_assert!(
+            mins.x <= maxs.x && mins.y <= maxs.y,
+            "AABB tuple must be (mins, maxs)"
+        );
+        FloatRect::new(mins.x, maxs.x, mins.y, maxs.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapter::FloatPointAdapter;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use nalgebra::{Point2, Vector2};
+
+    #[test]
+    fn test_0() {
+        let points = [
+            Point2::new(-2.0, -4.0),
+            Point2::new(5.0, 3.0),
+            Point2::new(1.25, 0.5),
+        ];
+        let adapter = FloatPointAdapter::<Point2<f64>, f64>::with_iter(points.iter());
+
+        let p = adapter.float_to_int(&points[2]);
+        assert_eq!(adapter.int_to_float(&p), points[2]);
+
+        let aabb: (Point2<f64>, Point2<f64>) = adapter.rect.into();
+        assert_eq!(aabb, (Point2::new(-2.0, -4.0), Point2::new(5.0, 3.0)));
+        assert_eq!(FloatRect::from(aabb), adapter.rect);
+    }
+
+    #[test]
+    fn test_1() {
+        let vectors = [Vector2::new(-1.0f32, 1.0), Vector2::new(1.0, -1.0)];
+        let adapter = FloatPointAdapter::<Vector2<f32>, f32>::with_iter(vectors.iter());
+        let p = adapter.float_to_int(&vectors[0]);
+        assert_eq!(adapter.int_to_float(&p), vectors[0]);
+
+        let q: Point2<i32> = IntPoint::new(-3, 8).into();
+        assert_eq!(IntPoint::from(q), IntPoint::new(-3, 8));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn test_2() {
+        let swapped = (Point2::new(5.0, 3.0), Point2::new(-2.0, -4.0));
+        let _ = FloatRect::<f64>::from(swapped);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::fix_angle::FixAngle;
+use crate::fix_float::{FIX_MAX, FIX_MIN};
+use crate::fix_vec::FixVec;
+use crate::float::number::FloatNumber;
+use crate::float::point::FloatPoint;
+use crate::float::rect::FloatRect;
+use crate::int::point::IntPoint;
+use crate::int::rect::IntRect;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+// Strategies that pick the edges of the requested range (min, min + 1, -1, 0, 1, max - 1, max)
+// a quarter of the time and a uniform value otherwise. The plain versions cover the whole
+// useful range of a type, the _in versions take explicit ranges.
+
+fn biased_i64(range: RangeInclusive<i64>) -> BoxedStrategy<i64> {
+    let (min, max) = (*range.start(), *range.end());
+    let mut edges: Vec<i64> = [min, min.saturating_add(1), -1, 0, 1, max.saturating_sub(1), max]
+        .into_iter()
+        .filter(|v| range.contains(v))
+        .collect();
+    edges.dedup();
+
+    prop_oneof![1 => select(edges), 3 => range].boxed()
+}
+
+fn biased_i32(range: RangeInclusive<i32>) -> BoxedStrategy<i32> {
+    biased_i64(*range.start() as i64..=*range.end() as i64)
+        .prop_map(|v| v as i32)
+        .boxed()
+}
+
+// sorted pair, every fourth one with a zero extent
+fn extent(range: RangeInclusive<i32>) -> impl Strategy<Value = (i32, i32)> {
+    prop_oneof![
+        1 => biased_i32(range.clone()).prop_map(|a| (a, a)),
+        3 => (biased_i32(range.clone()), biased_i32(range)).prop_map(|(a, b)| (a.min(b), a.max(b))),
+    ]
+}
+
+pub trait StrategyFloat: FloatNumber + core::fmt::Debug + 'static {
+    // any finite value, edges included
+    fn finite() -> BoxedStrategy<Self>;
+    fn biased(range: RangeInclusive<Self>) -> BoxedStrategy<Self>;
+}
+
+macro_rules! strategy_float {
+    ($t:ident) => {
+        impl StrategyFloat for $t {
+            fn finite() -> BoxedStrategy<Self> {
+                let edges = vec![
+                    0.0,
+                    -0.0,
+                    1.0,
+                    -1.0,
+                    $t::MIN_POSITIVE,
+                    -$t::MIN_POSITIVE,
+                    $t::MAX,
+                    $t::MIN,
+                ];
+                let any = proptest::num::$t::NORMAL | proptest::num::$t::SUBNORMAL | proptest::num::$t::ZERO;
+                prop_oneof![1 => select(edges), 3 => any].boxed()
+            }
+
+            fn biased(range: RangeInclusive<Self>) -> BoxedStrategy<Self> {
+                // the next value towards +inf, the std next_up needs Rust 1.86
+                fn next_up(x: $t) -> $t {
+                    if x.is_nan() || x == $t::INFINITY {
+                        x
+                    } else if x == 0.0 {
+                        $t::from_bits(1)
+                    } else if x > 0.0 {
+                        $t::from_bits(x.to_bits() + 1)
+                    } else {
+                        $t::from_bits(x.to_bits() - 1)
+                    }
+                }
+
+                let (min, max) = (*range.start(), *range.end());
+                let edges: Vec<$t> = [min, next_up(min), -1.0, 0.0, 1.0, -next_up(-max), max]
+                    .into_iter()
+                    .filter(|v| range.contains(v))
+                    .collect();
+                prop_oneof![1 => select(edges), 3 => range].boxed()
+            }
+        }
+    };
+}
+
+strategy_float!(f32);
+strategy_float!(f64);
+
+pub fn int_point() -> impl Strategy<Value = IntPoint> {
+    prop_oneof![
+        1 => Just(IntPoint::EMPTY),
+        15 => int_point_in(i32::MIN..=i32::MAX, i32::MIN..=i32::MAX),
+    ]
+}
+
+pub fn int_point_in(x: RangeInclusive<i32>, y: RangeInclusive<i32>) -> impl Strategy<Value = IntPoint> {
+    (biased_i32(x), biased_i32(y)).prop_map(|(x, y)| IntPoint::new(x, y))
+}
+
+// always valid, often degenerate
+pub fn int_rect() -> impl Strategy<Value = IntRect> {
+    int_rect_in(i32::MIN..=i32::MAX, i32::MIN..=i32::MAX)
+}
+
+pub fn int_rect_in(x: RangeInclusive<i32>, y: RangeInclusive<i32>) -> impl Strategy<Value = IntRect> {
+    (extent(x), extent(y))
+        .prop_map(|((min_x, max_x), (min_y, max_y))| IntRect::new(min_x, max_x, min_y, max_y))
+}
+
+// FIX_MIN..=FIX_MAX, the range where fix products do not overflow
+pub fn fix_vec() -> impl Strategy<Value = FixVec> {
+    fix_vec_in(FIX_MIN..=FIX_MAX, FIX_MIN..=FIX_MAX)
+}
+
+pub fn fix_vec_in(x: RangeInclusive<i64>, y: RangeInclusive<i64>) -> impl Strategy<Value = FixVec> {
+    (biased_i64(x), biased_i64(y)).prop_map(|(x, y)| FixVec::new(x, y))
+}
+
+// one full round is 1024, quarter turns are picked on purpose
+pub fn fix_angle() -> impl Strategy<Value = FixAngle> {
+    prop_oneof![
+        1 => select(vec![0, 255, 256, 511, 512, 767, 768, 1023]),
+        3 => 0..1024i64,
+    ]
+}
+
+pub fn fix_angle_in(range: RangeInclusive<FixAngle>) -> impl Strategy<Value = FixAngle> {
+    biased_i64(range)
+}
+
+pub fn float_point<T: StrategyFloat>() -> impl Strategy<Value = FloatPoint<T>> {
+    (T::finite(), T::finite()).prop_map(|(x, y)| FloatPoint::new(x, y))
+}
+
+pub fn float_point_in<T: StrategyFloat>(
+    x: RangeInclusive<T>,
+    y: RangeInclusive<T>,
+) -> impl Strategy<Value = FloatPoint<T>> {
+    (T::biased(x), T::biased(y)).prop_map(|(x, y)| FloatPoint::new(x, y))
+}
+
+pub fn float_rect<T: StrategyFloat>() -> impl Strategy<Value = FloatRect<T>> {
+    (float_point::<T>(), float_point::<T>()).prop_map(|(a, b)| FloatRect::with_ab(a, b))
+}
+
+pub fn float_rect_in<T: StrategyFloat>(
+    x: RangeInclusive<T>,
+    y: RangeInclusive<T>,
+) -> impl Strategy<Value = FloatRect<T>> {
+    (float_point_in(x.clone(), y.clone()), float_point_in(x, y)).prop_map(|(a, b)| FloatRect::with_ab(a, b))
+}
+
+impl Arbitrary for IntPoint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        int_point().boxed()
+    }
+}
+
+impl Arbitrary for IntRect {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        int_rect().boxed()
+    }
+}
+
+impl Arbitrary for FixVec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        fix_vec().boxed()
+    }
+}
+
+impl<T: StrategyFloat> Arbitrary for FloatPoint<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        float_point().boxed()
+    }
+}
+
+impl<T: StrategyFloat> Arbitrary for FloatRect<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        float_rect().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_angle::FixTrigonometry;
+    use crate::fix_vec::FixVec;
+    use crate::float::point::FloatPoint;
+    use crate::float::rect::FloatRect;
+    use crate::int::point::IntPoint;
+    use crate::int::rect::IntRect;
+    use crate::integration::proptest::{fix_angle, fix_vec_in, float_point_in, int_point_in, int_rect_in};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_0(p in int_point_in(-10..=10, 0..=5), r in int_rect_in(-3..=3, 7..=9)) {
+            prop_assert!((-10..=10).contains(&p.x) && (0..=5).contains(&p.y));
+            prop_assert!(r.min_x <= r.max_x && r.min_y <= r.max_y);
+            prop_assert!(r.min_x >= -3 && r.max_y <= 9);
+        }
+
+        #[test]
+        fn test_1(v in fix_vec_in(-1024..=1024, -1024..=1024), a in fix_angle()) {
+            prop_assert!(v.x.abs() <= 1024 && v.y.abs() <= 1024);
+            prop_assert_eq!(a.trim(), a);
+        }
+
+        #[test]
+        fn test_2(p in float_point_in(-1.0f64..=1.0, 2.0..=3.0), r in any::<FloatRect<f32>>()) {
+            prop_assert!(p.x.abs() <= 1.0 && (2.0..=3.0).contains(&p.y));
+            prop_assert!(r.is_valid() && r.is_finite());
+        }
+
+        #[test]
+        fn test_3(p in any::<IntPoint>(), r in any::<IntRect>(), v in any::<FixVec>(), f in any::<FloatPoint<f64>>()) {
+            prop_assert!(r.min_x <= r.max_x && r.min_y <= r.max_y);
+            prop_assert_eq!(IntPoint::from([p.x, p.y]), p);
+            prop_assert!(v.x.abs() <= 1 << 31);
+            prop_assert!(f.x.is_finite() && f.y.is_finite());
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use crate::fix_float::FixFloat;
+use crate::fix_vec::{ArchivedFixVec, FixVec};
+use crate::float::compatible::FloatPointCompatible;
+use crate::float::rect::{ArchivedFloatRect, FloatRect};
+use crate::int::point::{ArchivedIntPoint, IntPoint};
+use crate::int::rect::{ArchivedIntRect, IntRect};
+
+// Archived types answer the same queries as the native ones straight from the archive bytes.
+// Every accessor loads the few fields it needs and runs the native code, so the results match.
+
+impl ArchivedIntPoint {
+    #[inline(always)]
+    pub fn x(&self) -> i32 {
+        self.x.to_native()
+    }
+
+    #[inline(always)]
+    pub fn y(&self) -> i32 {
+        self.y.to_native()
+    }
+
+    #[inline(always)]
+    pub fn to_native(&self) -> IntPoint {
+        IntPoint::new(self.x(), self.y())
+    }
+
+    #[inline(always)]
+    pub fn cross_product(&self, v: &Self) -> i64 {
+        self.to_native().cross_product(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn dot_product(&self, v: &Self) -> i64 {
+        self.to_native().dot_product(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn subtract(&self, other: &Self) -> FixVec {
+        self.to_native().subtract(other.to_native())
+    }
+
+    #[inline(always)]
+    pub fn sqr_length(&self) -> i64 {
+        self.to_native().sqr_length()
+    }
+
+    #[inline(always)]
+    pub fn sqr_distance(&self, other: &Self) -> i64 {
+        self.to_native().sqr_distance(other.to_native())
+    }
+}
+
+impl From<&ArchivedIntPoint> for IntPoint {
+    #[inline(always)]
+    fn from(point: &ArchivedIntPoint) -> Self {
+        point.to_native()
+    }
+}
+
+impl ArchivedFixVec {
+    #[inline(always)]
+    pub fn x(&self) -> i64 {
+        self.x.to_native()
+    }
+
+    #[inline(always)]
+    pub fn y(&self) -> i64 {
+        self.y.to_native()
+    }
+
+    #[inline(always)]
+    pub fn to_native(&self) -> FixVec {
+        FixVec::new(self.x(), self.y())
+    }
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.to_native().is_zero()
+    }
+
+    #[inline(always)]
+    pub fn sqr_length(&self) -> i64 {
+        self.to_native().sqr_length()
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> FixFloat {
+        self.to_native().length()
+    }
+
+    #[inline(always)]
+    pub fn dot_product(&self, v: &Self) -> i64 {
+        self.to_native().dot_product(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn cross_product(&self, v: &Self) -> i64 {
+        self.to_native().cross_product(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn cross_product_sign(&self, v: &Self) -> i32 {
+        self.to_native().cross_product_sign(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn dot_product_sign(&self, v: &Self) -> i32 {
+        self.to_native().dot_product_sign(v.to_native())
+    }
+
+    #[inline(always)]
+    pub fn sqr_distance(&self, v: &Self) -> i64 {
+        self.to_native().sqr_distance(v.to_native())
+    }
+}
+
+impl From<&ArchivedFixVec> for FixVec {
+    #[inline(always)]
+    fn from(vec: &ArchivedFixVec) -> Self {
+        vec.to_native()
+    }
+}
+
+impl ArchivedIntRect {
+    #[inline(always)]
+    pub fn to_native(&self) -> IntRect {
+        IntRect::new(
+            self.min_x.to_native(),
+            self.max_x.to_native(),
+            self.min_y.to_native(),
+            self.max_y.to_native(),
+        )
+    }
+
+    #[inline(always)]
+    pub fn width(&self) -> i32 {
+        self.to_native().width()
+    }
+
+    #[inline(always)]
+    pub fn height(&self) -> i32 {
+        self.to_native().height()
+    }
+
+    #[inline(always)]
+    pub fn contains(&self, point: IntPoint) -> bool {
+        self.to_native().contains(point)
+    }
+
+    #[inline(always)]
+    pub fn contains_exclude_borders(&self, point: IntPoint) -> bool {
+        self.to_native().contains_exclude_borders(point)
+    }
+
+    #[inline(always)]
+    pub fn contains_with_radius(&self, point: IntPoint, radius: i32) -> bool {
+        self.to_native().contains_with_radius(point, radius)
+    }
+
+    #[inline]
+    pub fn is_intersect_border_include(&self, other: &IntRect) -> bool {
+        self.to_native().is_intersect_border_include(other)
+    }
+
+    #[inline]
+    pub fn is_intersect_border_exclude(&self, other: &IntRect) -> bool {
+        self.to_native().is_intersect_border_exclude(other)
+    }
+
+    #[inline]
+    pub fn contains_rect(&self, other: &IntRect) -> bool {
+        self.to_native().contains_rect(other)
+    }
+}
+
+impl From<&ArchivedIntRect> for IntRect {
+    #[inline(always)]
+    fn from(rect: &ArchivedIntRect) -> Self {
+        rect.to_native()
+    }
+}
+
+// rend stores f32 and f64 in distinct little endian types, so the float rect is done per type
+macro_rules! archived_float_rect {
+    ($t:ty) => {
+        impl ArchivedFloatRect<$t> {
+            #[inline(always)]
+            pub fn to_native(&self) -> FloatRect<$t> {
+                FloatRect::new(
+                    self.min_x.to_native(),
+                    self.max_x.to_native(),
+                    self.min_y.to_native(),
+                    self.max_y.to_native(),
+                )
+            }
+
+            #[inline(always)]
+            pub fn width(&self) -> $t {
+                self.to_native().width()
+            }
+
+            #[inline(always)]
+            pub fn height(&self) -> $t {
+                self.to_native().height()
+            }
+
+            #[inline(always)]
+            pub fn area(&self) -> $t {
+                self.to_native().area()
+            }
+
+            #[inline(always)]
+            pub fn contains<P: FloatPointCompatible<$t>>(&self, point: &P) -> bool {
+                self.to_native().contains(point)
+            }
+
+            #[inline(always)]
+            pub fn contains_exclude_borders<P: FloatPointCompatible<$t>>(&self, point: &P) -> bool {
+                self.to_native().contains_exclude_borders(point)
+            }
+
+            #[inline(always)]
+            pub fn contains_with_radius<P: FloatPointCompatible<$t>>(&self, point: &P, radius: $t) -> bool {
+                self.to_native().contains_with_radius(point, radius)
+            }
+
+            #[inline]
+            pub fn is_intersect_border_include(&self, other: &FloatRect<$t>) -> bool {
+                self.to_native().is_intersect_border_include(other)
+            }
+
+            #[inline]
+            pub fn is_intersect_border_exclude(&self, other: &FloatRect<$t>) -> bool {
+                self.to_native().is_intersect_border_exclude(other)
+            }
+
+            #[inline]
+            pub fn contains_rect(&self, other: &FloatRect<$t>) -> bool {
+                self.to_native().contains_rect(other)
+            }
+
+            #[inline(always)]
+            pub fn is_finite(&self) -> bool {
+                self.to_native().is_finite()
+            }
+        }
+
+        impl From<&ArchivedFloatRect<$t>> for FloatRect<$t> {
+            #[inline(always)]
+            fn from(rect: &ArchivedFloatRect<$t>) -> Self {
+                rect.to_native()
+            }
+        }
+    };
+}
+
+archived_float_rect!(f32);
+archived_float_rect!(f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::fix_vec::{ArchivedFixVec, FixVec};
+    use crate::float::rect::{ArchivedFloatRect, FloatRect};
+    use crate::int::point::{ArchivedIntPoint, IntPoint};
+    use crate::int::rect::{ArchivedIntRect, IntRect};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use rkyv::rancor::Error;
+
+    #[test]
+    fn test_0() {
+        let contour = vec![
+            IntPoint::new(0, 0),
+            IntPoint::new(10, 0),
+            IntPoint::new(10, 10),
+            IntPoint::new(i32::MIN, i32::MAX),
+        ];
+
+        let bytes = rkyv::to_bytes::<Error>(&contour).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Vec<IntPoint>>, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.len(), contour.len());
+        for (a, p) in archived.iter().zip(contour.iter()) {
+            assert_eq!(a.to_native(), *p);
+            assert_eq!(a.sqr_length(), p.sqr_length());
+        }
+
+        let a: &ArchivedIntPoint = &archived[1];
+        let b: &ArchivedIntPoint = &archived[2];
+        assert_eq!(a.cross_product(b), contour[1].cross_product(contour[2]));
+        assert_eq!(a.dot_product(b), 100);
+
+        let restored: Vec<IntPoint> = rkyv::deserialize::<Vec<IntPoint>, Error>(archived).unwrap();
+        assert_eq!(restored, contour);
+    }
+
+    #[test]
+    fn test_1() {
+        let rect = IntRect::new(-5, 5, -2, 2);
+        let bytes = rkyv::to_bytes::<Error>(&rect).unwrap();
+        let archived = rkyv::access::<ArchivedIntRect, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.width(), 10);
+        assert!(archived.contains(IntPoint::new(5, 2)));
+        assert!(!archived.contains_exclude_borders(IntPoint::new(5, 2)));
+        assert!(archived.contains_rect(&IntRect::new(-1, 1, -1, 1)));
+
+        let v = FixVec::new(3 << 10, -4 << 10);
+        let bytes = rkyv::to_bytes::<Error>(&v).unwrap();
+        let archived = rkyv::access::<ArchivedFixVec, Error>(&bytes).unwrap();
+        assert_eq!(archived.to_native(), v);
+        assert_eq!(archived.length(), v.length());
+        assert_eq!(archived.cross_product_sign(archived), 0);
+    }
+
+    #[test]
+    fn test_2() {
+        let rect = FloatRect::new(-1.5f64, 2.5, -3.0, 3.0);
+        let bytes = rkyv::to_bytes::<Error>(&rect).unwrap();
+        let archived = rkyv::access::<ArchivedFloatRect<f64>, Error>(&bytes).unwrap();
+
+        assert_eq!(archived.to_native(), rect);
+        assert_eq!(archived.area(), 24.0);
+        assert!(archived.contains(&[2.5, 0.0]));
+        assert!(!archived.contains(&[2.6, 0.0]));
+        assert!(archived.is_intersect_border_include(&FloatRect::new(2.5, 4.0, 0.0, 1.0)));
+    }
+}
@@ -4,6 +4,8 @@ extern crate alloc;
 #[cfg(feature = "core")]
 pub mod adapter;
 #[cfg(feature = "core")]
+pub mod bulk;
+#[cfg(feature = "core")]
 pub mod fix_angle;
 #[cfg(feature = "core")]
 pub mod fix_float;
@@ -12,7 +14,11 @@ mod fix_sin;
 #[cfg(feature = "core")]
 pub mod fix_vec;
 #[cfg(feature = "core")]
+pub mod i128;
+#[cfg(feature = "core")]
 pub mod int;
+#[cfg(all(feature = "core", feature = "serde"))]
+pub mod serde_compact;
 #[cfg(feature = "core")]
 pub mod triangle;
 #[cfg(feature = "core")]
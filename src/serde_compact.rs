@@ -0,0 +1,192 @@
+use crate::fix_vec::FixVec;
+use crate::float::number::FloatNumber;
+use crate::float::point::FloatPoint;
+use crate::float::rect::FloatRect;
+use crate::i128::Int128;
+use crate::int::point::IntPoint;
+use crate::int::point64::IntPoint64;
+use crate::int::rect::IntRect;
+use crate::u128::UInt128;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Opt-in tuple representation: IntPoint is written as [x, y] instead of {"x": .., "y": ..}.
+// The default derives stay untouched, pick the compact form per field:
+//
+// #[serde(with = "i_float::serde_compact")]
+// origin: IntPoint,
+// #[serde(with = "i_float::serde_compact::vec")]
+// path: Vec<IntPoint>,
+//
+// or wrap a value into Compact.
+
+pub trait CompactRepr: Sized {
+    type Repr: Serialize + DeserializeOwned;
+
+    fn to_repr(&self) -> Self::Repr;
+    fn from_repr(repr: Self::Repr) -> Self;
+}
+
+impl CompactRepr for IntPoint {
+    type Repr = [i32; 2];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        IntPoint::new(repr[0], repr[1])
+    }
+}
+
+impl CompactRepr for IntPoint64 {
+    type Repr = [i64; 2];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        IntPoint64::new(repr[0], repr[1])
+    }
+}
+
+impl CompactRepr for FixVec {
+    type Repr = [i64; 2];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        FixVec::new(repr[0], repr[1])
+    }
+}
+
+// [min_x, max_x, min_y, max_y], the field order of the struct
+impl CompactRepr for IntRect {
+    type Repr = [i32; 4];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.min_x, self.max_x, self.min_y, self.max_y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        IntRect::new(repr[0], repr[1], repr[2], repr[3])
+    }
+}
+
+impl<T: FloatNumber + Serialize + DeserializeOwned> CompactRepr for FloatPoint<T> {
+    type Repr = [T; 2];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.x, self.y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        FloatPoint::new(repr[0], repr[1])
+    }
+}
+
+impl<T: FloatNumber + Serialize + DeserializeOwned> CompactRepr for FloatRect<T> {
+    type Repr = [T; 4];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.min_x, self.max_x, self.min_y, self.max_y]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        FloatRect::new(repr[0], repr[1], repr[2], repr[3])
+    }
+}
+
+// [high, low]
+impl CompactRepr for UInt128 {
+    type Repr = [u64; 2];
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        [self.high, self.low]
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        UInt128::new(repr[0], repr[1])
+    }
+}
+
+// (high, low)
+impl CompactRepr for Int128 {
+    type Repr = (i64, u64);
+
+    #[inline(always)]
+    fn to_repr(&self) -> Self::Repr {
+        (self.high, self.low)
+    }
+
+    #[inline(always)]
+    fn from_repr(repr: Self::Repr) -> Self {
+        Int128::new(repr.0, repr.1)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Compact<T>(pub T);
+
+impl<T: CompactRepr> Serialize for Compact<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de, T: CompactRepr> Deserialize<'de> for Compact<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::Repr::deserialize(deserializer).map(|repr| Compact(T::from_repr(repr)))
+    }
+}
+
+#[inline]
+pub fn serialize<T: CompactRepr, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.to_repr().serialize(serializer)
+}
+
+#[inline]
+pub fn deserialize<'de, T: CompactRepr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    T::Repr::deserialize(deserializer).map(T::from_repr)
+}
+
+pub mod vec {
+    use crate::serde_compact::{Compact, CompactRepr};
+    use alloc::vec::Vec;
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: CompactRepr, S: Serializer>(values: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(values.len()))?;
+        for value in values.iter() {
+            seq.serialize_element(&value.to_repr())?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, T: CompactRepr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let values: Vec<Compact<T>> = Vec::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|c| c.0).collect())
+    }
+}
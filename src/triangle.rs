@@ -1,5 +1,6 @@
 use crate::fix_float::FixFloat;
 use crate::fix_vec::FixVec;
+use crate::i128::Int128;
 use crate::int::point::IntPoint;
 use core::cmp::Ordering;
 
@@ -70,6 +71,88 @@ impl Triangle {
         has_neg && has_pos
     }
 
+    // sign of (a - c) x (b - c), exact for any i64 coordinates
+    #[inline]
+    fn orientation_sign(a: FixVec, b: FixVec, c: FixVec) -> i32 {
+        // |coord| < 2^30 keeps every difference and product inside i64
+        let bits = a.x.unsigned_abs() | a.y.unsigned_abs() | b.x.unsigned_abs() | b.y.unsigned_abs();
+        if (bits | c.x.unsigned_abs() | c.y.unsigned_abs()) < 1 << 30 {
+            return (a - c).cross_product(b - c).signum() as i32;
+        }
+
+        // (a - c) x (b - c) = a x b + b x c + c x a, every product fits into Int128
+        // and |product| <= 2^126, so its negation does too
+        let products = [
+            Int128::mul(a.x, b.y),
+            -Int128::mul(a.y, b.x),
+            Int128::mul(b.x, c.y),
+            -Int128::mul(b.y, c.x),
+            Int128::mul(c.x, a.y),
+            -Int128::mul(c.y, a.x),
+        ];
+
+        Int128::sign_of_sum(&products)
+    }
+
+    #[inline]
+    pub fn area_two_sign(p0: FixVec, p1: FixVec, p2: FixVec) -> i32 {
+        Self::orientation_sign(p0, p2, p1)
+    }
+
+    #[inline]
+    pub fn is_clockwise_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        Self::area_two_sign(p0, p1, p2) > 0
+    }
+
+    #[inline]
+    pub fn is_cw_or_line_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        Self::area_two_sign(p0, p1, p2) >= 0
+    }
+
+    #[inline]
+    pub fn is_not_line_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        Self::area_two_sign(p0, p1, p2) != 0
+    }
+
+    #[inline]
+    pub fn is_line_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        Self::area_two_sign(p0, p1, p2) == 0
+    }
+
+    #[inline]
+    pub fn clock_direction_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> i64 {
+        Self::area_two_sign(p0, p1, p2) as i64
+    }
+
+    #[inline]
+    pub fn clock_order_exact(p0: FixVec, p1: FixVec, p2: FixVec) -> Ordering {
+        0.cmp(&Self::area_two_sign(p0, p1, p2))
+    }
+
+    #[inline]
+    pub fn is_contain_exact(p: FixVec, p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        let q0 = Self::orientation_sign(p, p0, p1);
+        let q1 = Self::orientation_sign(p, p1, p2);
+        let q2 = Self::orientation_sign(p, p2, p0);
+
+        let has_neg = q0 < 0 || q1 < 0 || q2 < 0;
+        let has_pos = q0 > 0 || q1 > 0 || q2 > 0;
+
+        !(has_neg && has_pos)
+    }
+
+    #[inline]
+    pub fn is_not_contain_exact(p: FixVec, p0: FixVec, p1: FixVec, p2: FixVec) -> bool {
+        let q0 = Self::orientation_sign(p, p0, p1);
+        let q1 = Self::orientation_sign(p, p1, p2);
+        let q2 = Self::orientation_sign(p, p2, p0);
+
+        let has_neg = q0 <= 0 || q1 <= 0 || q2 <= 0;
+        let has_pos = q0 >= 0 || q1 >= 0 || q2 >= 0;
+
+        has_neg && has_pos
+    }
+
     #[inline(always)]
     pub fn area_two_point(p0: IntPoint, p1: IntPoint, p2: IntPoint) -> i64 {
         let x0 = p1.x as i64 - p0.x as i64;
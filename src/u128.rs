@@ -1,5 +1,7 @@
 use core::cmp::Ordering;
+use core::{fmt, ops};
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct UInt128 {
     pub high: u64,
@@ -45,6 +47,281 @@ impl UInt128 {
     }
 }
 
+impl UInt128 {
+    pub const ZERO: Self = Self { high: 0, low: 0 };
+    pub const ONE: Self = Self { high: 0, low: 1 };
+    pub const MAX: Self = Self {
+        high: u64::MAX,
+        low: u64::MAX,
+    };
+
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.high == 0 && self.low == 0
+    }
+
+    #[inline(always)]
+    pub fn leading_zeros(&self) -> u32 {
+        if self.high != 0 {
+            self.high.leading_zeros()
+        } else {
+            u64::BITS + self.low.leading_zeros()
+        }
+    }
+
+    #[inline]
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let (low, carry) = self.low.overflowing_add(other.low);
+        let (high_0, overflow_0) = self.high.overflowing_add(other.high);
+        let (high, overflow_1) = high_0.overflowing_add(carry as u64);
+        (Self::new(high, low), overflow_0 || overflow_1)
+    }
+
+    #[inline]
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let (low, borrow) = self.low.overflowing_sub(other.low);
+        let (high_0, overflow_0) = self.high.overflowing_sub(other.high);
+        let (high, overflow_1) = high_0.overflowing_sub(borrow as u64);
+        (Self::new(high, low), overflow_0 || overflow_1)
+    }
+
+    #[inline(always)]
+    pub fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    #[inline(always)]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (value, false) => Some(value),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn checked_mul_u64(self, value: u64) -> Option<Self> {
+        let low = Self::multiply(self.low, value);
+        let high = Self::multiply(self.high, value);
+        if high.high != 0 {
+            return None;
+        }
+        low.checked_add(Self::new(high.low, 0))
+    }
+
+    #[inline]
+    pub fn checked_shl(self, shift: u32) -> Option<Self> {
+        if shift < u128::BITS {
+            Some(self.shl_bits(shift))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn checked_shr(self, shift: u32) -> Option<Self> {
+        if shift < u128::BITS {
+            Some(self.shr_bits(shift))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn shl_bits(self, shift: u32) -> Self {
+        match shift {
+            0 => self,
+            1..64 => Self::new(
+                (self.high << shift) | (self.low >> (u64::BITS - shift)),
+                self.low << shift,
+            ),
+            _ => Self::new(self.low << (shift - u64::BITS), 0),
+        }
+    }
+
+    #[inline]
+    fn shr_bits(self, shift: u32) -> Self {
+        match shift {
+            0 => self,
+            1..64 => Self::new(
+                self.high >> shift,
+                (self.low >> shift) | (self.high << (u64::BITS - shift)),
+            ),
+            _ => Self::new(0, self.high >> (shift - u64::BITS)),
+        }
+    }
+
+    #[inline]
+    pub fn div_rem_u64(self, divisor: u64) -> (Self, u64) {
+        let q_high = self.high / divisor;
+        let r_high = self.high % divisor;
+
+        // r_high < divisor, so the second quotient digit fits into u64
+        let (q_low, r) = Self::div_2by1(r_high, self.low, divisor);
+
+        (Self::new(q_high, q_low), r)
+    }
+
+    // (high, low) / divisor for high < divisor, long division with 32 bit digits
+    // (Hacker's Delight, divlu). The divisor is normalized so its top bit is set,
+    // then every estimated digit is at most 2 too large.
+    fn div_2by1(high: u64, low: u64, divisor: u64) -> (u64, u64) {
+        const B: u64 = 1 << 32;
+        const MASK: u64 = B - 1;
+
+        let s = divisor.leading_zeros();
+        let v = divisor << s;
+        let (vn1, vn0) = (v >> 32, v & MASK);
+
+        let un32 = if s == 0 {
+            high
+        } else {
+            (high << s) | (low >> (u64::BITS - s))
+        };
+        let un10 = low << s;
+        let (un1, un0) = (un10 >> 32, un10 & MASK);
+
+        let digit = |un: u64, next: u64| {
+            let mut q = un / vn1;
+            let mut rhat = un - q * vn1;
+            // q * vn0 is only evaluated for q < B, so it fits
+            while q >= B || q * vn0 > (rhat << 32) | next {
+                q -= 1;
+                rhat += vn1;
+                if rhat >= B {
+                    break;
+                }
+            }
+            q
+        };
+
+        let q1 = digit(un32, un1);
+        // the partial remainder is below v, the wrapped terms cancel
+        let un21 = (un32 << 32).wrapping_add(un1).wrapping_sub(q1.wrapping_mul(v));
+
+        let q0 = digit(un21, un0);
+        let r = (un21 << 32).wrapping_add(un0).wrapping_sub(q0.wrapping_mul(v));
+
+        ((q1 << 32) | q0, r >> s)
+    }
+
+    #[inline]
+    pub fn checked_div_rem_u64(self, divisor: u64) -> Option<(Self, u64)> {
+        if divisor == 0 {
+            None
+        } else {
+            Some(self.div_rem_u64(divisor))
+        }
+    }
+
+    pub fn isqrt(self) -> u64 {
+        if self.high == 0 {
+            return self.low.isqrt();
+        }
+
+        // the f64 estimate is off by at most a few thousands, start above the root
+        // and let Newton's method walk down to it
+        let value = self.high as f64 * 18_446_744_073_709_551_616.0 + self.low as f64;
+        let estimate = libm::sqrt(value) as u64;
+        let mut x = estimate.saturating_add(1 << 12);
+
+        loop {
+            let (q, _) = self.div_rem_u64(x);
+            if q.high != 0 {
+                // only possible for x = u64::MAX, which is then the root
+                return x;
+            }
+            // (x + q) / 2 without the carry of the sum
+            let y = (x >> 1) + (q.low >> 1) + (x & q.low & 1);
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+}
+
+impl From<u64> for UInt128 {
+    #[inline(always)]
+    fn from(value: u64) -> Self {
+        Self::new(0, value)
+    }
+}
+
+impl From<u128> for UInt128 {
+    #[inline(always)]
+    fn from(value: u128) -> Self {
+        Self::new((value >> 64) as u64, value as u64)
+    }
+}
+
+impl From<UInt128> for u128 {
+    #[inline(always)]
+    fn from(value: UInt128) -> Self {
+        ((value.high as u128) << 64) | value.low as u128
+    }
+}
+
+impl ops::Add for UInt128 {
+    type Output = UInt128;
+
+    #[inline(always)]
+    fn add(self, other: UInt128) -> UInt128 {
+        let (value, overflow) = self.overflowing_add(other);
+        debug_assert!(!overflow, "attempt to add with overflow");
+        value
+    }
+}
+
+impl ops::Sub for UInt128 {
+    type Output = UInt128;
+
+    #[inline(always)]
+    fn sub(self, other: UInt128) -> UInt128 {
+        let (value, overflow) = self.overflowing_sub(other);
+        debug_assert!(!overflow, "attempt to subtract with overflow");
+        value
+    }
+}
+
+impl ops::Shl<u32> for UInt128 {
+    type Output = UInt128;
+
+    #[inline(always)]
+    fn shl(self, shift: u32) -> UInt128 {
+        debug_assert!(shift < u128::BITS, "attempt to shift left with overflow");
+        self.shl_bits(shift & (u128::BITS - 1))
+    }
+}
+
+impl ops::Shr<u32> for UInt128 {
+    type Output = UInt128;
+
+    #[inline(always)]
+    fn shr(self, shift: u32) -> UInt128 {
+        debug_assert!(shift < u128::BITS, "attempt to shift right with overflow");
+        self.shr_bits(shift & (u128::BITS - 1))
+    }
+}
+
+impl fmt::Display for UInt128 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", u128::from(*self))
+    }
+}
+
 impl PartialOrd for UInt128 {
     #[inline(always)]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -1,3 +1,5 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use i_float::fix_angle::{FixAngle, FixTrigonometry};
 use i_float::fix_float::{FixConvert, FixMath};
 use std::f64::consts::PI;
@@ -23,3 +23,34 @@ fn test_function_2() {
 
     assert_eq!(a.length(), 5.fix());
 }
+
+#[test]
+fn test_function_3() {
+    let a = FixVec::new(i64::MAX, i64::MAX - 1);
+    let b = FixVec::new(i64::MAX - 1, i64::MAX - 2);
+
+    // (2^63 - 1)(2^63 - 3) - (2^63 - 2)^2 = -1
+    assert_eq!(a.cross_product_sign(b), -1);
+    assert_eq!(b.cross_product_sign(a), 1);
+    assert_eq!(a.cross_product_sign(a), 0);
+}
+
+#[test]
+fn test_function_4() {
+    let a = FixVec::new(i64::MIN, i64::MAX);
+    let b = FixVec::new(i64::MAX, i64::MAX);
+
+    // -2^63 (2^63 - 1) + (2^63 - 1)^2 = -(2^63 - 1)
+    assert_eq!(a.dot_product_sign(b), -1);
+    assert_eq!(a.dot_product_sign(FixVec::new(-1, -1)), 1);
+    assert_eq!(FixVec::new(1, 0).dot_product_sign(FixVec::new(0, i64::MIN)), 0);
+}
+
+#[test]
+fn test_function_5() {
+    let a = FixVec::new(3, -7);
+    let b = FixVec::new(-5, 2);
+
+    assert_eq!(a.cross_product_sign(b) as i64, a.cross_product(b).signum());
+    assert_eq!(a.dot_product_sign(b) as i64, a.dot_product(b).signum());
+}
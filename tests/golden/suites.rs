@@ -0,0 +1,388 @@
+// Golden vector suites, shared by tests/golden_tests.rs and examples/generate_golden.rs.
+//
+// Every suite owns one CSV file in tests/golden. A row holds the inputs followed by the
+// expected outputs, integers in decimal and floats as hex bit patterns, so the files are
+// exact on every platform. Inputs are only produced by the generator, the tests read them
+// back from the files and recompute the outputs.
+
+#![allow(dead_code)]
+
+use i_float::adapter::FloatPointAdapter;
+use i_float::fix_angle::{FixAngle, FixTrigonometry};
+use i_float::fix_float::{FIX_MAX, FIX_MIN, FixFloat, FixMath};
+use i_float::fix_vec::FixVec;
+use i_float::float::rect::FloatRect;
+use i_float::int::point::IntPoint;
+use i_float::triangle::Triangle;
+
+pub struct Suite {
+    pub name: &'static str,
+    pub header: &'static str,
+    pub input_count: usize,
+    pub inputs: fn() -> Vec<Vec<String>>,
+    pub eval: fn(&[&str]) -> Vec<String>,
+}
+
+pub const SUITES: [Suite; 9] = [
+    Suite {
+        name: "fix_mul",
+        header: "a,b,a_fix_mul_b",
+        input_count: 2,
+        inputs: fix_pair_inputs,
+        eval: eval_fix_mul,
+    },
+    Suite {
+        name: "fix_div",
+        header: "a,b,a_fix_div_b",
+        input_count: 2,
+        inputs: fix_div_inputs,
+        eval: eval_fix_div,
+    },
+    Suite {
+        name: "fix_sqrt",
+        header: "a,fix_sqrt",
+        input_count: 1,
+        inputs: fix_sqrt_inputs,
+        eval: eval_fix_sqrt,
+    },
+    Suite {
+        name: "trigonometry",
+        header: "angle,sin,cos,rotator_x,rotator_y",
+        input_count: 1,
+        inputs: angle_inputs,
+        eval: eval_trigonometry,
+    },
+    Suite {
+        name: "fix_normalize",
+        header: "x,y,normal_x,normal_y",
+        input_count: 2,
+        inputs: normalize_inputs,
+        eval: eval_normalize,
+    },
+    Suite {
+        name: "triangle",
+        header: "x0,y0,x1,y1,x2,y2,px,py,clock_direction,is_clockwise,is_cw_or_line,is_line,is_contain,is_not_contain",
+        input_count: 8,
+        inputs: triangle_inputs,
+        eval: eval_triangle,
+    },
+    Suite {
+        name: "triangle_exact",
+        header: "x0,y0,x1,y1,x2,y2,px,py,area_two_sign,is_contain_exact,is_not_contain_exact",
+        input_count: 8,
+        inputs: triangle_exact_inputs,
+        eval: eval_triangle_exact,
+    },
+    Suite {
+        name: "adapter_f64",
+        header: "min_x,max_x,min_y,max_y,x,y,int_x,int_y,back_x,back_y",
+        input_count: 6,
+        inputs: adapter_f64_inputs,
+        eval: eval_adapter_f64,
+    },
+    Suite {
+        name: "adapter_f32",
+        header: "min_x,max_x,min_y,max_y,x,y,int_x,int_y,back_x,back_y",
+        input_count: 6,
+        inputs: adapter_f32_inputs,
+        eval: eval_adapter_f32,
+    },
+];
+
+pub fn golden_dir() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+pub fn render(suite: &Suite) -> String {
+    let mut text = String::new();
+    text.push_str(suite.header);
+    text.push('\n');
+    for inputs in (suite.inputs)() {
+        let refs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        let outputs = (suite.eval)(&refs);
+        text.push_str(&inputs.join(","));
+        text.push(',');
+        text.push_str(&outputs.join(","));
+        text.push('\n');
+    }
+    text
+}
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        let span = (max as i128 - min as i128 + 1) as u128;
+        (min as i128 + (self.next() as u128 % span) as i128) as i64
+    }
+
+    fn unit(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn int(value: i64) -> String {
+    value.to_string()
+}
+
+fn flag(value: bool) -> String {
+    (value as u8).to_string()
+}
+
+fn f64_hex(value: f64) -> String {
+    format!("{:016x}", value.to_bits())
+}
+
+fn f32_hex(value: f32) -> String {
+    format!("{:08x}", value.to_bits())
+}
+
+fn parse_int(text: &str) -> i64 {
+    text.parse().unwrap()
+}
+
+fn parse_f64(text: &str) -> f64 {
+    f64::from_bits(u64::from_str_radix(text, 16).unwrap())
+}
+
+fn parse_f32(text: &str) -> f32 {
+    f32::from_bits(u32::from_str_radix(text, 16).unwrap())
+}
+
+// fix values, FIX_MIN..=FIX_MAX keeps the products inside i64
+const FIX_EDGES: [FixFloat; 9] = [FIX_MIN, -1025, -1024, -1, 0, 1, 1024, 1025, FIX_MAX];
+
+fn fix_pair_inputs() -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    for &a in FIX_EDGES.iter() {
+        for &b in FIX_EDGES.iter() {
+            rows.push(vec![int(a), int(b)]);
+        }
+    }
+    let mut random = Random(0x1234_5678_9ABC_DEF1);
+    for i in 0..1000 {
+        // every second pair stays small to cover the common range densely
+        let limit = if i % 2 == 0 { 1 << 20 } else { FIX_MAX };
+        rows.push(vec![
+            int(random.range(-limit, limit)),
+            int(random.range(-limit, limit)),
+        ]);
+    }
+    rows
+}
+
+fn eval_fix_mul(inputs: &[&str]) -> Vec<String> {
+    vec![int(parse_int(inputs[0]).fix_mul(parse_int(inputs[1])))]
+}
+
+fn fix_div_inputs() -> Vec<Vec<String>> {
+    fix_pair_inputs()
+        .into_iter()
+        .filter(|row| row[1] != "0")
+        .collect()
+}
+
+fn eval_fix_div(inputs: &[&str]) -> Vec<String> {
+    vec![int(parse_int(inputs[0]).fix_div(parse_int(inputs[1])))]
+}
+
+fn fix_sqrt_inputs() -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = (0..=4096).map(|a| vec![int(a)]).collect();
+    let mut random = Random(0x0F0F_1234_AAAA_5555);
+    for _ in 0..1000 {
+        rows.push(vec![int(random.range(0, FIX_MAX))]);
+    }
+    rows.push(vec![int(FIX_MAX)]);
+    rows
+}
+
+fn eval_fix_sqrt(inputs: &[&str]) -> Vec<String> {
+    vec![int(parse_int(inputs[0]).fix_sqrt())]
+}
+
+fn angle_inputs() -> Vec<Vec<String>> {
+    // one full round is 1024, plus negative and multi round angles
+    let mut rows: Vec<Vec<String>> = (-1024..=2048).map(|a| vec![int(a)]).collect();
+    for a in [i32::MIN as i64, -1_000_001, 1_000_001, i32::MAX as i64] {
+        rows.push(vec![int(a)]);
+    }
+    rows
+}
+
+fn eval_trigonometry(inputs: &[&str]) -> Vec<String> {
+    let angle: FixAngle = parse_int(inputs[0]);
+    let rotator = angle.rotator();
+    vec![int(angle.sin()), int(angle.cos()), int(rotator.x), int(rotator.y)]
+}
+
+fn normalize_inputs() -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let limit = 1 << 20;
+    for (x, y) in [
+        (1024, 0),
+        (0, -1024),
+        (limit, limit),
+        (-limit, 1),
+        (64, 64),
+        (3 << 10, 4 << 10),
+    ] {
+        rows.push(vec![int(x), int(y)]);
+    }
+    let mut random = Random(0xDEAD_BEEF_0BAD_F00D);
+    while rows.len() < 1000 {
+        let x = random.range(-limit, limit);
+        let y = random.range(-limit, limit);
+        // the fix length of shorter vectors is too coarse to normalize
+        if x.abs().max(y.abs()) >= 64 {
+            rows.push(vec![int(x), int(y)]);
+        }
+    }
+    rows
+}
+
+fn eval_normalize(inputs: &[&str]) -> Vec<String> {
+    let v = FixVec::new(parse_int(inputs[0]), parse_int(inputs[1])).fix_normalize();
+    vec![int(v.x), int(v.y)]
+}
+
+fn triangle_rows(seed: u64, limit: i64) -> Vec<Vec<String>> {
+    let mut random = Random(seed);
+    let mut rows = Vec::new();
+    for i in 0..1000 {
+        // a small grid gives plenty of collinear triples and points on edges
+        let l = if i % 2 == 0 { 4 } else { limit };
+        let row: Vec<String> = (0..8).map(|_| int(random.range(-l, l))).collect();
+        rows.push(row);
+    }
+    rows
+}
+
+fn triangle_points(inputs: &[&str]) -> [FixVec; 4] {
+    let v: Vec<i64> = inputs.iter().map(|s| parse_int(s)).collect();
+    [
+        FixVec::new(v[0], v[1]),
+        FixVec::new(v[2], v[3]),
+        FixVec::new(v[4], v[5]),
+        FixVec::new(v[6], v[7]),
+    ]
+}
+
+fn triangle_inputs() -> Vec<Vec<String>> {
+    // the i64 predicates need coordinates within 2^29 to keep the cross products in range
+    let limit = 1 << 29;
+    let mut rows = triangle_rows(0x5555_AAAA_1234_4321, limit);
+    let edges = [-limit, 0, limit];
+    for &a in edges.iter() {
+        for &b in edges.iter() {
+            rows.push([a, b, b, a, a, a, b, b].iter().map(|&v| int(v)).collect());
+        }
+    }
+    rows
+}
+
+fn eval_triangle(inputs: &[&str]) -> Vec<String> {
+    let [p0, p1, p2, p] = triangle_points(inputs);
+    vec![
+        int(Triangle::clock_direction(p0, p1, p2)),
+        flag(Triangle::is_clockwise(p0, p1, p2)),
+        flag(Triangle::is_cw_or_line(p0, p1, p2)),
+        flag(Triangle::is_line(p0, p1, p2)),
+        flag(Triangle::is_contain(p, p0, p1, p2)),
+        flag(Triangle::is_not_contain(p, p0, p1, p2)),
+    ]
+}
+
+fn triangle_exact_inputs() -> Vec<Vec<String>> {
+    let mut rows = triangle_rows(0x7777_1111_BBBB_2222, i64::MAX);
+    let edges = [i64::MIN, -1, 0, i64::MAX];
+    for &a in edges.iter() {
+        for &b in edges.iter() {
+            rows.push([a, a, b, a, a, b, b, b].iter().map(|&v| int(v)).collect());
+        }
+    }
+    rows
+}
+
+fn eval_triangle_exact(inputs: &[&str]) -> Vec<String> {
+    let [p0, p1, p2, p] = triangle_points(inputs);
+    vec![
+        int(Triangle::area_two_sign(p0, p1, p2) as i64),
+        flag(Triangle::is_contain_exact(p, p0, p1, p2)),
+        flag(Triangle::is_not_contain_exact(p, p0, p1, p2)),
+    ]
+}
+
+fn adapter_rows(seed: u64) -> Vec<[f64; 6]> {
+    let mut random = Random(seed);
+    let mut rows = Vec::new();
+    for i in 0..1000 {
+        // rect sizes from 1e-3 up to 1e7
+        let scale = 10f64.powi(i % 11 - 3);
+        let x0 = (random.unit() - 0.5) * scale;
+        let y0 = (random.unit() - 0.5) * scale;
+        let w = random.unit() * scale;
+        let h = random.unit() * scale;
+        let x = x0 + random.unit() * w;
+        let y = y0 + random.unit() * h;
+        rows.push([x0, x0 + w, y0, y0 + h, x, y]);
+    }
+    rows
+}
+
+fn adapter_f64_inputs() -> Vec<Vec<String>> {
+    adapter_rows(0x2468_ACE0_1357_9BDF)
+        .iter()
+        .map(|row| row.iter().map(|&v| f64_hex(v)).collect())
+        .collect()
+}
+
+fn eval_adapter_f64(inputs: &[&str]) -> Vec<String> {
+    let v: Vec<f64> = inputs.iter().map(|s| parse_f64(s)).collect();
+    let adapter = FloatPointAdapter::<[f64; 2], f64>::new(FloatRect::new(v[0], v[1], v[2], v[3]));
+    let p: IntPoint = adapter.float_to_int(&[v[4], v[5]]);
+    let back = adapter.int_to_float(&p);
+    vec![
+        int(p.x as i64),
+        int(p.y as i64),
+        f64_hex(back[0]),
+        f64_hex(back[1]),
+    ]
+}
+
+fn adapter_f32_inputs() -> Vec<Vec<String>> {
+    adapter_rows(0x1357_9BDF_2468_ACE0)
+        .iter()
+        .map(|row| {
+            // rounding may push a point out of the f32 rect, keep it inside
+            let r: Vec<f32> = row.iter().map(|&v| v as f32).collect();
+            let x = r[4].clamp(r[0], r[1]);
+            let y = r[5].clamp(r[2], r[3]);
+            [r[0], r[1], r[2], r[3], x, y]
+                .iter()
+                .map(|&v| f32_hex(v))
+                .collect()
+        })
+        .collect()
+}
+
+fn eval_adapter_f32(inputs: &[&str]) -> Vec<String> {
+    let v: Vec<f32> = inputs.iter().map(|s| parse_f32(s)).collect();
+    let adapter = FloatPointAdapter::<[f32; 2], f32>::new(FloatRect::new(v[0], v[1], v[2], v[3]));
+    let p: IntPoint = adapter.float_to_int(&[v[4], v[5]]);
+    let back = adapter.int_to_float(&p);
+    vec![
+        int(p.x as i64),
+        int(p.y as i64),
+        f32_hex(back[0]),
+        f32_hex(back[1]),
+    ]
+}
@@ -0,0 +1,80 @@
+// Checks the exact outputs against tests/golden, see examples/generate_golden.rs to update them.
+
+#[path = "golden/suites.rs"]
+mod suites;
+
+use suites::{SUITES, Suite};
+
+fn check(suite: &Suite) {
+    let path = suites::golden_dir().join(format!("{}.csv", suite.name));
+    let text = std::fs::read_to_string(&path).unwrap();
+    let mut lines = text.lines();
+
+    assert_eq!(lines.next(), Some(suite.header), "{}: header", suite.name);
+
+    let mut count = 0;
+    for (index, line) in lines.enumerate() {
+        let columns: Vec<&str> = line.split(',').collect();
+        let (inputs, expected) = columns.split_at(suite.input_count);
+        let actual = (suite.eval)(inputs);
+        assert_eq!(actual, expected, "{} row {}: {}", suite.name, index + 1, line);
+        count += 1;
+    }
+
+    assert!(count > 0, "{}: no rows", suite.name);
+}
+
+#[test]
+fn test_fix_mul() {
+    check(&SUITES[0]);
+}
+
+#[test]
+fn test_fix_div() {
+    check(&SUITES[1]);
+}
+
+#[test]
+fn test_fix_sqrt() {
+    check(&SUITES[2]);
+}
+
+#[test]
+fn test_trigonometry() {
+    check(&SUITES[3]);
+}
+
+#[test]
+fn test_fix_normalize() {
+    check(&SUITES[4]);
+}
+
+#[test]
+fn test_triangle() {
+    check(&SUITES[5]);
+}
+
+#[test]
+fn test_triangle_exact() {
+    check(&SUITES[6]);
+}
+
+#[test]
+fn test_adapter_f64() {
+    check(&SUITES[7]);
+}
+
+#[test]
+fn test_adapter_f32() {
+    check(&SUITES[8]);
+}
+
+#[test]
+fn test_inputs_are_stable() {
+    // the files must hold exactly what the generator produces, otherwise they were edited by hand
+    for suite in SUITES.iter() {
+        let path = suites::golden_dir().join(format!("{}.csv", suite.name));
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text == suites::render(suite), "{} is out of date", suite.name);
+    }
+}
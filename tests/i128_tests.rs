@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use i_float::i128::Int128;
+
+    fn random_values(count: usize) -> Vec<i64> {
+        let mut result = vec![
+            0,
+            1,
+            -1,
+            i64::MAX,
+            i64::MIN,
+            i64::MIN + 1,
+            i32::MAX as i64,
+            i32::MIN as i64,
+        ];
+        let mut seed = 0x9E37_79B9_7F4A_7C15_u64;
+        for _ in 0..count {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            result.push((seed as i64) >> (seed % 64));
+        }
+        result
+    }
+
+    #[test]
+    fn test_0() {
+        let result = Int128::mul(-3, 4);
+        assert_eq!(i128::from(result), -12);
+        assert!(result.is_negative());
+        assert_eq!(result.signum(), -1);
+    }
+
+    #[test]
+    fn test_1() {
+        assert_eq!(i128::from(Int128::mul(i64::MIN, i64::MIN)), 1 << 126);
+        assert_eq!(
+            i128::from(Int128::mul(i64::MIN, i64::MAX)),
+            i64::MIN as i128 * i64::MAX as i128
+        );
+        assert_eq!(i128::from(Int128::mul(i64::MAX, -1)), -(i64::MAX as i128));
+        assert_eq!(Int128::mul(0, i64::MIN), Int128::ZERO);
+    }
+
+    #[test]
+    fn test_mul() {
+        let values = random_values(100);
+        for &a in values.iter() {
+            for &b in values.iter() {
+                assert_eq!(i128::from(Int128::mul(a, b)), a as i128 * b as i128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let values = random_values(40);
+        for &a in values.iter() {
+            for &b in values.iter() {
+                for &c in values.iter().take(8) {
+                    let x = Int128::mul(a, b);
+                    let y = Int128::mul(b, c);
+                    let nx = a as i128 * b as i128;
+                    let ny = b as i128 * c as i128;
+
+                    assert_eq!(x.checked_add(y).map(i128::from), nx.checked_add(ny));
+                    assert_eq!(x.checked_sub(y).map(i128::from), nx.checked_sub(ny));
+                    assert_eq!(x.cmp(&y), nx.cmp(&ny));
+                    assert_eq!(x.signum(), nx.signum() as i32);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        assert!(Int128::MAX.checked_add(Int128::from(1i64)).is_none());
+        assert!(Int128::MIN.checked_sub(Int128::from(1i64)).is_none());
+        assert!(Int128::MIN.checked_neg().is_none());
+        assert_eq!(i128::from(Int128::MIN), i128::MIN);
+        assert_eq!(i128::from(Int128::MAX), i128::MAX);
+        assert_eq!(u128::from(Int128::MIN.unsigned_abs()), 1 << 127);
+        assert_eq!(Int128::from(i128::MIN + 5), Int128::MIN + Int128::from(5i64));
+        assert_eq!(-Int128::from(-7i64), Int128::from(7i64));
+    }
+
+    #[test]
+    fn test_cmp_products() {
+        let values = random_values(30);
+        for &a in values.iter() {
+            for &b in values.iter() {
+                for &c in values.iter() {
+                    for &d in values.iter().take(10) {
+                        let ab = a as i128 * b as i128;
+                        let cd = c as i128 * d as i128;
+                        assert_eq!(Int128::cmp_products(a, b, c, d), ab.cmp(&cd));
+
+                        let det = a as i128 * d as i128 - b as i128 * c as i128;
+                        assert_eq!(Int128::sign_of_det2(a, b, c, d), det.signum() as i32);
+
+                        let dot = (a as i128 * c as i128).checked_add(b as i128 * d as i128);
+                        let dot_sign = dot.map(|v| v.signum() as i32).unwrap_or(1);
+                        assert_eq!(Int128::sign_of_dot2(a, b, c, d), dot_sign);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign_of_dot2_limit() {
+        // 2^127 does not fit into i128, the sign is still exact
+        assert_eq!(Int128::sign_of_dot2(i64::MIN, i64::MIN, i64::MIN, i64::MIN), 1);
+        assert_eq!(Int128::sign_of_dot2(i64::MIN, i64::MIN, i64::MIN, i64::MAX), 1);
+        assert_eq!(Int128::sign_of_dot2(i64::MIN, i64::MAX, i64::MAX, i64::MIN), -1);
+    }
+
+    #[test]
+    fn test_sign_of_sum() {
+        let big = Int128::MAX;
+        let one = Int128::from(1i64);
+        // the partial sum wraps above i128::MAX and comes back
+        assert_eq!(Int128::sign_of_sum(&[big, one, -big]), 1);
+        assert_eq!(Int128::sign_of_sum(&[big, big, -big, -big]), 0);
+        assert_eq!(Int128::sign_of_sum(&[Int128::MIN, -one, big]), -1);
+        assert_eq!(Int128::sign_of_sum(&[big, big]), 1);
+        assert_eq!(Int128::sign_of_sum(&[Int128::MIN, Int128::MIN]), -1);
+        assert_eq!(Int128::sign_of_sum(&[]), 0);
+    }
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "serde")]
+
+use i_float::adapter::FloatPointAdapter;
+use i_float::adapter::anisotropic::AnisotropicFloatPointAdapter;
+use i_float::fix_vec::FixVec;
+use i_float::float::point::FloatPoint;
+use i_float::float::rect::FloatRect;
+use i_float::i128::Int128;
+use i_float::int::point::IntPoint;
+use i_float::int::point64::IntPoint64;
+use i_float::int::rational::RationalPoint;
+use i_float::int::rect::IntRect;
+use i_float::serde_compact::Compact;
+use i_float::u128::UInt128;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+fn json_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let text = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&text).unwrap()
+}
+
+fn binary_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let bytes = bincode::serialize(value).unwrap();
+    bincode::deserialize(&bytes).unwrap()
+}
+
+fn assert_roundtrip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+    assert_eq!(json_roundtrip(&value), value);
+    assert_eq!(binary_roundtrip(&value), value);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Scene {
+    #[serde(with = "i_float::serde_compact")]
+    origin: IntPoint,
+    #[serde(with = "i_float::serde_compact")]
+    bounds: FloatRect<f64>,
+    #[serde(with = "i_float::serde_compact::vec")]
+    path: Vec<IntPoint>,
+}
+
+#[test]
+fn test_value_types() {
+    assert_roundtrip(IntPoint::new(i32::MIN, i32::MAX));
+    assert_roundtrip(IntPoint64::new(i64::MIN, i64::MAX));
+    assert_roundtrip(FixVec::new(-1 << 40, 1 << 40));
+    assert_roundtrip(FloatRect::new(-1.5, 2.25, f64::MIN_POSITIVE, f64::MAX));
+    assert_roundtrip(UInt128::new(u64::MAX, 7));
+    assert_roundtrip(Int128::MIN);
+    assert_roundtrip(RationalPoint::new(3, -7, 11));
+
+    // deserialization goes through the checked constructor
+    let p: RationalPoint = serde_json::from_str(r#"{"x":6,"y":-9,"den":-12}"#).unwrap();
+    assert_eq!(p, RationalPoint::new(-2, 3, 4));
+    assert!(serde_json::from_str::<RationalPoint>(r#"{"x":1,"y":2,"den":0}"#).is_err());
+
+    let rect = IntRect::new(-3, 4, -5, 6);
+    let restored = binary_roundtrip(&json_roundtrip(&rect));
+    assert_eq!(
+        (restored.min_x, restored.max_x, restored.min_y, restored.max_y),
+        (-3, 4, -5, 6)
+    );
+
+    let point = json_roundtrip(&binary_roundtrip(&FloatPoint::new(0.1f32, -0.2)));
+    assert_eq!((point.x, point.y), (0.1, -0.2));
+}
+
+#[test]
+fn test_adapters() {
+    let points = [[-2.3, -4.1], [-2.0, 3.7], [5.9, 3.0]];
+    let adapter = FloatPointAdapter::<[f64; 2], f64>::with_iter(points.iter());
+
+    for restored in [json_roundtrip(&adapter), binary_roundtrip(&adapter)] {
+        for p in points.iter() {
+            assert_eq!(restored.float_to_int(p), adapter.float_to_int(p));
+        }
+    }
+
+    let rect = FloatRect::new(-1.0, 1.0, -10.0, 10.0);
+    let adapter = AnisotropicFloatPointAdapter::<[f64; 2], f64>::new(rect);
+    for restored in [json_roundtrip(&adapter), binary_roundtrip(&adapter)] {
+        assert_eq!(restored.dir_scale_x, adapter.dir_scale_x);
+        assert_eq!(restored.dir_scale_y, adapter.dir_scale_y);
+        assert_eq!(restored.rect, adapter.rect);
+    }
+}
+
+#[test]
+fn test_compact() {
+    assert_eq!(
+        serde_json::to_string(&IntPoint::new(1, -2)).unwrap(),
+        r#"{"x":1,"y":-2}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&Compact(IntPoint::new(1, -2))).unwrap(),
+        "[1,-2]"
+    );
+    assert_eq!(
+        serde_json::to_string(&Compact(IntRect::new(-1, 1, -2, 2))).unwrap(),
+        "[-1,1,-2,2]"
+    );
+    assert_eq!(
+        serde_json::to_string(&Compact(UInt128::new(1, 2))).unwrap(),
+        "[1,2]"
+    );
+
+    let scene = Scene {
+        origin: IntPoint::new(10, 20),
+        bounds: FloatRect::new(0.0, 1.5, -2.0, 2.0),
+        path: vec![
+            IntPoint::new(0, 0),
+            IntPoint::new(5, -5),
+            IntPoint::new(i32::MAX, i32::MIN),
+        ],
+    };
+
+    let text = serde_json::to_string(&scene).unwrap();
+    assert_eq!(
+        text,
+        r#"{"origin":[10,20],"bounds":[0.0,1.5,-2.0,2.0],"path":[[0,0],[5,-5],[2147483647,-2147483648]]}"#
+    );
+
+    assert_roundtrip::<Scene>(serde_json::from_str(&text).unwrap());
+
+    // the compact form is smaller in binary formats as well, there are no field names to skip
+    let compact = bincode::serialize(&Compact(FixVec::new(1, 2))).unwrap();
+    assert_eq!(compact.len(), 16);
+    let point: Compact<FixVec> = bincode::deserialize(&compact).unwrap();
+    assert_eq!(point.0, FixVec::new(1, 2));
+}
@@ -0,0 +1,114 @@
+use i_float::fix_vec::FixVec;
+use i_float::triangle::Triangle;
+use std::cmp::Ordering;
+
+const MIN: i64 = i64::MIN;
+const MAX: i64 = i64::MAX;
+
+fn random_points(count: usize, bits: u32) -> Vec<FixVec> {
+    let mut result = Vec::with_capacity(count);
+    let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+    for _ in 0..count {
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed as i64) >> (64 - bits)
+        };
+        result.push(FixVec::new(next(), next()));
+    }
+    result
+}
+
+fn reference_area_two(p0: FixVec, p1: FixVec, p2: FixVec) -> i128 {
+    let ax = p1.x as i128 - p0.x as i128;
+    let ay = p1.y as i128 - p0.y as i128;
+    let bx = p1.x as i128 - p2.x as i128;
+    let by = p1.y as i128 - p2.y as i128;
+    ax * by - ay * bx
+}
+
+#[test]
+fn test_0() {
+    // small coordinates take the i64 path and must agree with the old predicates
+    let points = random_points(30, 24);
+    for &p0 in points.iter() {
+        for &p1 in points.iter() {
+            for &p2 in points.iter().take(10) {
+                assert_eq!(
+                    Triangle::clock_direction_exact(p0, p1, p2),
+                    Triangle::clock_direction(p0, p1, p2)
+                );
+                assert_eq!(
+                    Triangle::is_clockwise_exact(p0, p1, p2),
+                    Triangle::is_clockwise(p0, p1, p2)
+                );
+                assert_eq!(
+                    Triangle::is_contain_exact(p0, p1, p2, points[0]),
+                    Triangle::is_contain(p0, p1, p2, points[0])
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_1() {
+    // 61 bit coordinates overflow i64 but the reference still fits into i128
+    let points = random_points(30, 62);
+    for &p0 in points.iter() {
+        for &p1 in points.iter() {
+            for &p2 in points.iter().take(10) {
+                let expected = reference_area_two(p0, p1, p2).signum() as i32;
+                assert_eq!(Triangle::area_two_sign(p0, p1, p2), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_2() {
+    let p0 = FixVec::new(MIN, MIN);
+    let p1 = FixVec::new(MAX, MIN);
+    let p2 = FixVec::new(MIN, MAX);
+
+    assert!(!Triangle::is_clockwise_exact(p0, p1, p2));
+    assert!(Triangle::is_clockwise_exact(p0, p2, p1));
+    assert_eq!(Triangle::clock_order_exact(p0, p1, p2), Ordering::Greater);
+    assert!(Triangle::is_not_line_exact(p0, p1, p2));
+}
+
+#[test]
+fn test_3() {
+    let p0 = FixVec::new(MIN, MIN);
+    let p1 = FixVec::new(0, 0);
+    let p2 = FixVec::new(MAX, MAX);
+
+    assert!(Triangle::is_line_exact(p0, p1, p2));
+    assert!(Triangle::is_cw_or_line_exact(p0, p1, p2));
+
+    // one unit off the diagonal at the far end of the range
+    let p3 = FixVec::new(MAX, MAX - 1);
+    assert!(Triangle::is_not_line_exact(p0, p1, p3));
+    assert_eq!(
+        Triangle::clock_direction_exact(p0, p1, p3),
+        -Triangle::clock_direction_exact(p0, p3, p1)
+    );
+}
+
+#[test]
+fn test_4() {
+    let p0 = FixVec::new(MIN, MIN);
+    let p1 = FixVec::new(MAX, MIN);
+    let p2 = FixVec::new(MIN, MAX);
+
+    assert!(Triangle::is_contain_exact(FixVec::new(-1, -1), p0, p1, p2));
+    assert!(Triangle::is_contain_exact(p1, p0, p1, p2));
+    assert!(Triangle::is_not_contain_exact(FixVec::new(MAX, MAX), p0, p1, p2));
+    assert!(!Triangle::is_contain_exact(FixVec::new(MAX, MAX), p0, p1, p2));
+
+    // the hypotenuse passes through (-1, 0) and (0, -1)
+    assert!(Triangle::is_contain_exact(FixVec::new(-1, 0), p0, p1, p2));
+    assert!(!Triangle::is_contain_exact(FixVec::new(0, 0), p0, p1, p2));
+    assert!(!Triangle::is_contain_exact(FixVec::new(1, 0), p0, p1, p2));
+}
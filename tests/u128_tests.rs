@@ -113,4 +113,131 @@ mod tests {
         assert_eq!(result.high, 0x15d3ef338213a);
         assert_eq!(result.low, 0x65039ef3cbc5c42b);
     }
+
+    fn random_values(count: usize) -> Vec<u128> {
+        let mut result = vec![0, 1, u64::MAX as u128, 1 << 64, u128::MAX, u128::MAX - 1];
+        let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+        for _ in 0..count {
+            let mut next = || {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed
+            };
+            let a = next() as u128;
+            let b = next() as u128;
+            let shift = next() % 128;
+            result.push(((a << 64) | b) >> shift);
+        }
+        result
+    }
+
+    #[test]
+    fn test_native_0() {
+        for v in random_values(100) {
+            let a = UInt128::from(v);
+            assert_eq!(u128::from(a), v);
+            assert_eq!(a.leading_zeros(), v.leading_zeros());
+        }
+    }
+
+    #[test]
+    fn test_add_sub() {
+        let values = random_values(50);
+        for &a in values.iter() {
+            for &b in values.iter() {
+                let ua = UInt128::from(a);
+                let ub = UInt128::from(b);
+                assert_eq!(ua.checked_add(ub).map(u128::from), a.checked_add(b));
+                assert_eq!(ua.checked_sub(ub).map(u128::from), a.checked_sub(b));
+                assert_eq!(u128::from(ua.wrapping_add(ub)), a.wrapping_add(b));
+                assert_eq!(u128::from(ua.wrapping_sub(ub)), a.wrapping_sub(b));
+                assert_eq!(ua.cmp(&ub), a.cmp(&b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift() {
+        for v in random_values(50) {
+            let a = UInt128::from(v);
+            for shift in 0..128 {
+                assert_eq!(u128::from(a << shift), v << shift);
+                assert_eq!(u128::from(a >> shift), v >> shift);
+            }
+            assert!(a.checked_shl(128).is_none());
+            assert!(a.checked_shr(200).is_none());
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let values = random_values(100);
+        for &v in values.iter() {
+            for &d in values.iter() {
+                let d = d as u64;
+                let a = UInt128::from(v);
+                if d == 0 {
+                    assert!(a.checked_div_rem_u64(d).is_none());
+                    continue;
+                }
+                let (q, r) = a.div_rem_u64(d);
+                assert_eq!(u128::from(q), v / d as u128);
+                assert_eq!(r as u128, v % d as u128);
+            }
+
+            // divisors at the normalization and digit boundaries
+            for d in [
+                1,
+                3,
+                0xFFFF_FFFF,
+                1 << 32,
+                (1 << 32) + 1,
+                1 << 63,
+                u64::MAX - 1,
+                u64::MAX,
+            ] {
+                let (q, r) = UInt128::from(v).div_rem_u64(d);
+                assert_eq!(u128::from(q), v / d as u128);
+                assert_eq!(r as u128, v % d as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_u64() {
+        let values = random_values(50);
+        for &v in values.iter() {
+            for &m in values.iter() {
+                let m = m as u64;
+                let a = UInt128::from(v);
+                assert_eq!(a.checked_mul_u64(m).map(u128::from), v.checked_mul(m as u128));
+            }
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for v in random_values(500) {
+            let root = UInt128::from(v).isqrt() as u128;
+            assert!(root * root <= v);
+            assert!((root + 1).checked_mul(root + 1).is_none_or(|sqr| sqr > v));
+        }
+        assert_eq!(UInt128::MAX.isqrt(), u64::MAX);
+        assert_eq!(UInt128::from(1u128 << 64).isqrt(), 1 << 32);
+    }
+
+    #[test]
+    fn test_sum_of_products() {
+        // a * b + c * d compared with e * f, all exact
+        let a = 0xFFFF_FFFF_FFFF_FFF0;
+        let b = 0xFFFF_FFFF_FFFF_FF00;
+        let c = 0xF0;
+        let d = 0xFFFF_FFFF_FFFF_FFFF;
+
+        let sum = UInt128::multiply(a, b) + UInt128::multiply(c, d);
+        let native = a as u128 * b as u128 + c as u128 * d as u128;
+        assert_eq!(u128::from(sum), native);
+        assert!(sum > UInt128::multiply(a, b));
+    }
 }